@@ -0,0 +1,61 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use aoc_rust::day_factory::{all_days, DayEntry};
+use aoc_rust::utils::cache::InputCache;
+use aoc_rust::utils::fetch_input::get_example_data;
+
+/// The cached real input of the day, or its example when the input was never downloaded.
+fn bench_input(entry: &DayEntry) -> Option<(&'static str, String)> {
+    let day = entry.day();
+    let cached = InputCache::resolve().and_then(|cache| cache.read(entry.year, day));
+    if let Ok(Some(input)) = cached {
+        return Some(("input", input));
+    }
+    get_example_data(entry.year, day).map(|example| ("example", example))
+}
+
+fn bench_days(c: &mut Criterion) {
+    let days: Vec<(&DayEntry, &'static str, String)> = all_days().into_iter()
+        .filter_map(|entry| match bench_input(entry) {
+            Some((source, input)) => Some((entry, source, input)),
+            None => {
+                eprintln!("Skipping {} day{}: no input nor example", entry.year, entry.day());
+                None
+            }
+        })
+        .collect();
+
+    let mut parse_group = c.benchmark_group("parse");
+    for (entry, source, input) in &days {
+        let id = BenchmarkId::new(format!("{}/day{}", entry.year, entry.day()), source);
+        parse_group.bench_with_input(id, input.as_str(), |b, input| {
+            b.iter(|| entry.solver.parse(black_box(input)))
+        });
+    }
+    parse_group.finish();
+
+    for part in [1, 2] {
+        let mut part_group = c.benchmark_group(format!("part{part}"));
+        for (entry, source, input) in &days {
+            // A part that fails would only measure how fast it fails
+            let parsed = match entry.solver.parse(input) {
+                Ok(parsed) if entry.solver.run_part(part, &parsed).is_ok() => parsed,
+                _ => {
+                    eprintln!("Skipping {} day{} part{part}: it doesn't solve its {source}", entry.year, entry.day());
+                    continue;
+                }
+            };
+
+            let id = BenchmarkId::new(format!("{}/day{}", entry.year, entry.day()), source);
+            part_group.bench_with_input(id, &parsed, |b, parsed| {
+                b.iter(|| entry.solver.run_part(part, black_box(parsed)))
+            });
+        }
+        part_group.finish();
+    }
+}
+
+criterion_group!(benches, bench_days);
+criterion_main!(benches);
@@ -0,0 +1,246 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::utils::answer::Answer;
+use crate::utils::submit::{Hint, Outcome};
+
+pub const DEFAULT_ANSWERS_FILE: &str = "answers.json";
+pub const DEFAULT_SUBMISSIONS_FILE: &str = "submissions.json";
+
+/// Loads a JSON file, a missing file being the default value.
+fn load_json<T: DeserializeOwned + Default>(file_path: &str, what: &str) -> Result<T, String> {
+    let content = match fs::read_to_string(file_path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(T::default()),
+        Err(e) => return Err(format!("Unable to read the {what} {file_path}: {e}"))
+    };
+    serde_json::from_str(&content)
+        .map_err(|e| format!("The {what} {file_path} are not valid: {e}"))
+}
+
+fn save_json<T: Serialize>(value: &T, file_path: &str, what: &str) -> Result<(), String> {
+    let content = serde_json::to_string_pretty(value)
+        .map_err(|e| format!("Unable to serialize the {what}: {e}"))?;
+    fs::write(file_path, content + "\n")
+        .map_err(|e| format!("Unable to write the {what} {file_path}: {e}"))
+}
+
+/// Known correct answers, by year, day and part.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AnswerBook {
+    years: BTreeMap<i16, BTreeMap<i8, BTreeMap<i8, String>>>
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail { expected } => write!(f, "FAIL (expected {expected})"),
+            Verdict::Unknown => write!(f, "UNKNOWN")
+        }
+    }
+}
+
+/// How many answers got each verdict.
+#[derive(Debug, Default, PartialEq)]
+pub struct Tally {
+    pub passed: usize,
+    pub failed: usize,
+    pub unknown: usize
+}
+
+impl Tally {
+    pub fn add(&mut self, verdict: &Verdict) {
+        match verdict {
+            Verdict::Pass => self.passed += 1,
+            Verdict::Fail { .. } => self.failed += 1,
+            Verdict::Unknown => self.unknown += 1
+        }
+    }
+}
+
+impl fmt::Display for Tally {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} passed, {} failed, {} unknown", self.passed, self.failed, self.unknown)
+    }
+}
+
+impl AnswerBook {
+    /// Loads the answers, a missing file being an empty book.
+    pub fn load(file_path: &str) -> Result<AnswerBook, String> {
+        load_json(file_path, "answers")
+    }
+
+    pub fn save(&self, file_path: &str) -> Result<(), String> {
+        save_json(self, file_path, "answers")
+    }
+
+    pub fn get(&self, year: i16, day: i8, part: i8) -> Option<&str> {
+        self.years.get(&year)?
+            .get(&day)?
+            .get(&part)
+            .map(String::as_str)
+    }
+
+    pub fn check(&self, year: i16, day: i8, part: i8, answer: &Answer) -> Verdict {
+        match self.get(year, day, part) {
+            None => Verdict::Unknown,
+            Some(expected) if *expected == answer.to_string() => Verdict::Pass,
+            Some(expected) => Verdict::Fail { expected: expected.to_string() }
+        }
+    }
+
+    /// Records an accepted answer, replacing the previous one if any.
+    pub fn record(&mut self, year: i16, day: i8, part: i8, answer: &Answer) {
+        self.years.entry(year).or_default()
+            .entry(day).or_default()
+            .insert(part, answer.to_string());
+    }
+}
+
+/// An answer posted to the site, `at` being in seconds since the Unix epoch.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Submission {
+    pub answer: String,
+    pub outcome: Outcome,
+    pub at: u64
+}
+
+/// Every answer posted to the site, by year, day and part.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SubmissionLog {
+    years: BTreeMap<i16, BTreeMap<i8, BTreeMap<i8, Vec<Submission>>>>
+}
+
+impl SubmissionLog {
+    pub fn load(file_path: &str) -> Result<SubmissionLog, String> {
+        load_json(file_path, "submissions")
+    }
+
+    pub fn save(&self, file_path: &str) -> Result<(), String> {
+        save_json(self, file_path, "submissions")
+    }
+
+    pub fn submissions(&self, year: i16, day: i8, part: i8) -> &[Submission] {
+        self.years.get(&year)
+            .and_then(|days| days.get(&day))
+            .and_then(|parts| parts.get(&part))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    pub fn record(&mut self, year: i16, day: i8, part: i8, submission: Submission) {
+        self.years.entry(year).or_default()
+            .entry(day).or_default()
+            .entry(part).or_default()
+            .push(submission);
+    }
+
+    /// Until when the site refuses any answer, after the last submission asked to wait.
+    fn wait_until(&self) -> Option<u64> {
+        self.years.values()
+            .flat_map(|days| days.values())
+            .flat_map(|parts| parts.values())
+            .flatten()
+            .filter_map(|submission| match submission.outcome {
+                Outcome::Wrong { wait_secs: Some(wait_secs), .. } | Outcome::TooRecent { wait_secs: Some(wait_secs) } => Some(submission.at + wait_secs),
+                _ => None
+            })
+            .max()
+    }
+
+    /// Why `answer` should not be submitted, if it is known to be wrong or the site still asks to wait.
+    pub fn refusal(&self, year: i16, day: i8, part: i8, answer: &str, now: u64) -> Option<String> {
+        let number = answer.parse::<i128>().ok();
+        for submission in self.submissions(year, day, part) {
+            let refused = submission.answer.parse::<i128>().ok();
+            match (&submission.outcome, number, refused) {
+                (Outcome::Right, _, _) => return Some(format!("This part was already solved with {}", submission.answer)),
+                (Outcome::Wrong { .. }, _, _) if submission.answer == answer => {
+                    return Some(format!("The answer {answer} was already refused"));
+                },
+                (Outcome::Wrong { hint: Some(Hint::TooHigh), .. }, Some(number), Some(refused)) if number >= refused => {
+                    return Some(format!("The answer {answer} is too high, {refused} already was"));
+                },
+                (Outcome::Wrong { hint: Some(Hint::TooLow), .. }, Some(number), Some(refused)) if number <= refused => {
+                    return Some(format!("The answer {answer} is too low, {refused} already was"));
+                },
+                _ => {}
+            }
+        }
+
+        match self.wait_until() {
+            Some(until) if now < until => Some(format!("The site asked to wait, {}s left", until - now)),
+            _ => None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::{AnswerBook, Submission, SubmissionLog, Verdict};
+    use crate::utils::answer::Answer;
+    use crate::utils::submit::{Hint, Outcome};
+
+    #[test]
+    fn it_test_check() {
+        let mut book = AnswerBook::default();
+        book.record(2025, 1, 1, &Answer::from(1147_i64));
+
+        assert_eq!(book.check(2025, 1, 1, &Answer::from(1147_u64)), Verdict::Pass);
+        assert_eq!(book.check(2025, 1, 1, &Answer::from(42_i64)), Verdict::Fail { expected: "1147".to_string() });
+        assert_eq!(book.check(2025, 1, 2, &Answer::from(42_i64)), Verdict::Unknown);
+        assert_eq!(book.check(2024, 1, 1, &Answer::from(1147_i64)), Verdict::Unknown);
+    }
+
+    #[test]
+    fn it_test_save_and_load() {
+        let file_path = std::env::temp_dir().join(format!("aoc_answers_{}.json", std::process::id()));
+        let file_path = file_path.to_str().expect("The temp dir should be valid UTF-8");
+        let mut book = AnswerBook::default();
+        book.record(2025, 12, 1, &Answer::from("#.#\n.#."));
+        book.save(file_path).expect("The answers should be saved");
+
+        let loaded = AnswerBook::load(file_path).expect("The answers should be loaded");
+        std::fs::remove_file(file_path).expect("The answers should be removed");
+
+        assert_eq!(loaded, book);
+        assert_eq!(loaded.get(2025, 12, 1), Some("#.#\n.#."));
+        assert_eq!(AnswerBook::load(file_path), Ok(AnswerBook::default()));
+    }
+
+    #[test]
+    fn it_test_refusal() {
+        let mut log = SubmissionLog::default();
+        log.record(2025, 4, 1, Submission {
+            answer: "1000".to_string(),
+            outcome: Outcome::Wrong { hint: Some(Hint::TooHigh), wait_secs: Some(60) },
+            at: 100
+        });
+        log.record(2025, 4, 1, Submission {
+            answer: "10".to_string(),
+            outcome: Outcome::Wrong { hint: Some(Hint::TooLow), wait_secs: None },
+            at: 200
+        });
+        log.record(2025, 4, 2, Submission { answer: "9401".to_string(), outcome: Outcome::Right, at: 300 });
+
+        assert!(log.refusal(2025, 4, 1, "1000", 1000).is_some());
+        assert!(log.refusal(2025, 4, 1, "1200", 1000).is_some());
+        assert!(log.refusal(2025, 4, 1, "5", 1000).is_some());
+        assert!(log.refusal(2025, 4, 2, "9401", 1000).is_some());
+        assert!(log.refusal(2025, 4, 1, "564", 150).is_some());
+        assert_eq!(log.refusal(2025, 4, 1, "564", 1000), None);
+        assert_eq!(log.refusal(2025, 5, 1, "abc", 1000), None);
+    }
+}
@@ -0,0 +1,222 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::utils::day::Solver;
+use crate::utils::day_error::DayError;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Stats> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let len = sorted.len();
+        let median = if len.is_multiple_of(2) { (sorted[len/2 - 1] + sorted[len/2]) / 2 } else { sorted[len/2] };
+
+        let mean_secs = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / len as f64;
+        let variance = sorted.iter()
+            .map(|sample| (sample.as_secs_f64() - mean_secs).powi(2))
+            .sum::<f64>() / len as f64;
+
+        Some(Stats {
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean_secs),
+            stddev: Duration::from_secs_f64(variance.sqrt())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2
+}
+
+impl Phase {
+    pub fn of_part(part: i8) -> Phase {
+        if part == 1 { Phase::Part1 } else { Phase::Part2 }
+    }
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part1 => write!(f, "part1"),
+            Phase::Part2 => write!(f, "part2")
+        }
+    }
+}
+
+pub struct DayBench {
+    pub day: i8,
+    pub phases: Vec<(Phase, Result<Stats, DayError>)>
+}
+
+/// Runs `f` `warmup` times, then measures `runs` more runs. Stops at the first error.
+fn measure<T>(runs: usize, warmup: usize, mut f: impl FnMut() -> Result<T, DayError>) -> Result<Stats, DayError> {
+    for _ in 0..warmup {
+        f()?;
+    }
+
+    let mut samples = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let start = Instant::now();
+        f()?;
+        samples.push(start.elapsed());
+    }
+    Stats::from_samples(&samples).ok_or_else(|| DayError::solver("There was no run to measure"))
+}
+
+/// Benchmarks the parsing and the given parts of a day. The parts all share the same parsed input.
+pub fn bench_day(solver: &dyn Solver, input: &str, parts: &[i8], runs: usize, warmup: usize) -> DayBench {
+    let day = solver.get_nb();
+    let mut phases = vec![(Phase::Parse, measure(runs, warmup, || solver.parse(input)))];
+
+    match solver.parse(input) {
+        Ok(parsed) => {
+            for part in parts {
+                phases.push((Phase::of_part(*part), measure(runs, warmup, || solver.run_part(*part, &parsed))));
+            }
+        },
+        Err(error) => {
+            // The parse phase already reports the error, the parts can't run without an input
+            for part in parts {
+                phases.push((Phase::of_part(*part), Err(DayError::solver(format!("The input couldn't be parsed: {error}")))));
+            }
+        }
+    }
+
+    DayBench { day, phases }
+}
+
+/// Medians saved by a previous bench, in nanoseconds, by year, day and phase.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    years: BTreeMap<i16, BTreeMap<i8, BTreeMap<String, u64>>>
+}
+
+impl Baseline {
+    pub fn load(file_path: &str) -> Result<Baseline, String> {
+        let content = fs::read_to_string(file_path)
+            .map_err(|e| format!("Unable to read the baseline {file_path}: {e}"))?;
+        serde_json::from_str(&content)
+            .map_err(|e| format!("The baseline {file_path} is not valid: {e}"))
+    }
+
+    pub fn save(&self, file_path: &str) -> Result<(), String> {
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Unable to serialize the baseline: {e}"))?;
+        fs::write(file_path, content)
+            .map_err(|e| format!("Unable to write the baseline {file_path}: {e}"))
+    }
+
+    pub fn median(&self, year: i16, day: i8, phase: Phase) -> Option<Duration> {
+        self.years.get(&year)?
+            .get(&day)?
+            .get(&phase.to_string())
+            .map(|nanos| Duration::from_nanos(*nanos))
+    }
+
+    pub fn record(&mut self, year: i16, bench: &DayBench) {
+        let day_medians = self.years.entry(year).or_default().entry(bench.day).or_default();
+        for (phase, stats) in &bench.phases {
+            if let Ok(stats) = stats {
+                day_medians.insert(phase.to_string(), stats.median.as_nanos() as u64);
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Comparison {
+    Regression(f64),
+    Improvement(f64),
+    Stable(f64)
+}
+
+/// Compares a median with its baseline, `threshold` being a percentage.
+pub fn compare(median: Duration, baseline: Duration, threshold: f64) -> Comparison {
+    let change = (median.as_secs_f64() / baseline.as_secs_f64().max(f64::MIN_POSITIVE) - 1.0) * 100.0;
+    if change > threshold {
+        Comparison::Regression(change)
+    } else if change < -threshold {
+        Comparison::Improvement(change)
+    } else {
+        Comparison::Stable(change)
+    }
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Comparison::Regression(change) => write!(f, "REGRESSION {change:+.1}%"),
+            Comparison::Improvement(change) => write!(f, "improved {change:+.1}%"),
+            Comparison::Stable(change) => write!(f, "{change:+.1}%")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::bench::{bench_day, compare, Baseline, Comparison, Phase, Stats};
+    use crate::days::y2025::day1::Day1;
+
+    #[test]
+    fn it_test_stats() {
+        let samples: Vec<Duration> = [4, 1, 3, 2].iter().map(|ms| Duration::from_millis(*ms)).collect();
+        let stats = Stats::from_samples(&samples).expect("There should be stats");
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        assert_eq!(stats.stddev.as_micros(), 1118);
+        assert!(Stats::from_samples(&[]).is_none());
+    }
+
+    #[test]
+    fn it_test_compare() {
+        let baseline = Duration::from_millis(100);
+
+        assert!(matches!(compare(Duration::from_millis(150), baseline, 10.0), Comparison::Regression(c) if (c - 50.0).abs() < 1e-6));
+        assert!(matches!(compare(Duration::from_millis(50), baseline, 10.0), Comparison::Improvement(c) if (c + 50.0).abs() < 1e-6));
+        assert!(matches!(compare(Duration::from_millis(105), baseline, 10.0), Comparison::Stable(_)));
+    }
+
+    #[test]
+    fn it_test_bench_and_record() {
+        let bench = bench_day(&Day1, "L68\nL30\nR48", &[1, 2], 3, 1);
+        let mut baseline = Baseline::default();
+        baseline.record(2025, &bench);
+
+        assert_eq!(bench.phases.len(), 3);
+        assert!(bench.phases.iter().all(|(_, stats)| stats.is_ok()));
+        assert!(baseline.median(2025, 1, Phase::Part2).is_some());
+        assert!(baseline.median(2025, 2, Phase::Part2).is_none());
+    }
+
+    #[test]
+    fn it_test_bench_invalid_input() {
+        let bench = bench_day(&Day1, "X68", &[1], 3, 1);
+
+        assert!(bench.phases.iter().all(|(_, stats)| stats.is_err()));
+    }
+}
@@ -0,0 +1,684 @@
+use std::fmt;
+use std::num::NonZeroUsize;
+use std::thread;
+use std::time::Duration;
+
+use crate::answers::{DEFAULT_ANSWERS_FILE, DEFAULT_SUBMISSIONS_FILE};
+use crate::report::OutputFormat;
+
+pub const DEFAULT_YEAR: i16 = 2025;
+
+pub const USAGE: &str = "Usage: aoc_rust [COMMAND] [DAYS] [PART] [OPTIONS]
+
+Commands:
+  run    Run the days and print their answers [default]
+  bench  Run each part several times and print timing statistics
+  verify Run the days and check their answers against the known ones
+  submit Run a part of a day and post its answer to the site. Needs a day and a part
+  login  Check a session cookie read from the standard input and save it into the user config
+  whoami Print the user of the current session
+  examples  Extract the examples and their answers from the puzzle pages into ./examples/<YEAR>
+  cache  Manage the downloaded inputs: cache list, cache clear [DAYS] or cache refresh [DAYS]
+  new    Create the module of a new day in src/days/y<YEAR> and register it. Needs a day
+  watch  Rebuild and run the examples then the input of a day each time its source, input or examples change. Needs a day
+  tui    Show the calendar of the year in the terminal, to run the days and their examples. Needs the tui feature
+
+The session cookie is read from AOC_SESSION, then from the user config
+($AOC_CONFIG or ~/.config/aoc/config.toml), then from ./.aoc-session.
+The inputs are cached in the cache_dir of the config, or in $XDG_CACHE_HOME/aoc (~/.cache/aoc).
+What the solvers log goes to the standard error, set RUST_LOG=debug (or trace) to see it.
+
+Arguments:
+  [DAYS]  Days to run, as a list and/or ranges. Ex: 4 or 1,3,5-8. Runs all days when omitted.
+  [PART]  Part to run, 1 or 2. Same as --part. Runs both parts when omitted.
+
+Options:
+  -p, --part <PART>    Only run the given part (1 or 2)
+  -i, --input <FILE>   Read the puzzle input from FILE instead of the input cache (single day only)
+  -e, --example        Run against the saved example ./examples/<YEAR>/day<N>_1.txt instead of the real input
+  -y, --year <YEAR>    Year of the puzzles to run [default: 2025]
+  -q, --quiet          Only print the results
+  -l, --list           List the registered days of the year
+  -j, --jobs <N>       Load all the inputs first, then solve the days and their parts in parallel on N threads
+  -t, --timeout <SECS> Give up a part after SECS seconds of wall-clock time, it is then reported as stopped
+  -f, --format <FORMAT>  Print the results of run as text, json (one object per part), markdown or junit [default: text]
+  -h, --help           Print this help
+
+Bench options:
+  --runs <N>               Number of measured runs of each phase [default: 10]
+  --warmup <N>             Number of runs before measuring [default: 2]
+  --baseline <FILE>        Compare the medians with a saved baseline and flag the regressions
+  --save-baseline <FILE>   Save the medians as a baseline
+  --threshold <PERCENT>    Slowdown above which a phase is a regression [default: 10]
+
+Verify options:
+  --answers <FILE>   File of the known answers [default: answers.json]
+  --accept           Record the answers that are not known yet as the correct ones
+
+Submit options:
+  --log <FILE>       Log of the submitted answers, to never post a known wrong one again [default: submissions.json]
+
+Examples options:
+  --page <FILE>      Read the puzzle page from FILE instead of the cached or downloaded one (single day only)
+  --force            Replace the examples already saved
+
+New options:
+  --fetch            Also download the input and extract the examples of the new day
+
+Tui keys:
+  r to run the selected day, e to run its examples, o to open its input in $PAGER,
+  f to download its input again and q to quit. The answers are checked against --answers.
+
+Watch options:
+  --interval <MS>    How often the files are checked, in milliseconds [default: 500]
+  --once             Run once and print one line per part, as read by the watch loop
+
+Ex: cargo run 4 2 will run the part 2 of the day 4, cargo run -- 1-3 --part 1 the part 1 of the days 1 to 3.";
+
+#[derive(Debug, PartialEq)]
+pub struct CliError {
+    pub message: String
+}
+
+impl CliError {
+    fn new(message: String) -> CliError {
+        CliError { message }
+    }
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+#[derive(Debug, PartialEq, Default)]
+pub enum Command {
+    #[default]
+    Run,
+    Bench,
+    Verify,
+    Submit,
+    Login,
+    Whoami,
+    Examples,
+    Cache(CacheAction),
+    New,
+    Watch,
+    Tui
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum CacheAction {
+    List,
+    Clear,
+    Refresh
+}
+
+impl CacheAction {
+    fn from_word(word: &str) -> Option<CacheAction> {
+        match word {
+            "list" => Some(CacheAction::List),
+            "clear" => Some(CacheAction::Clear),
+            "refresh" => Some(CacheAction::Refresh),
+            _ => None
+        }
+    }
+}
+
+impl Command {
+    /// Whether the command runs the solvers of the year, and so needs some registered days.
+    pub fn runs_solvers(&self) -> bool {
+        matches!(self, Command::Run | Command::Bench | Command::Verify | Command::Submit | Command::Watch | Command::Tui)
+    }
+
+    fn from_word(word: &str) -> Option<Command> {
+        match word {
+            "run" => Some(Command::Run),
+            "bench" => Some(Command::Bench),
+            "verify" => Some(Command::Verify),
+            "submit" => Some(Command::Submit),
+            "login" => Some(Command::Login),
+            "whoami" => Some(Command::Whoami),
+            "examples" => Some(Command::Examples),
+            "cache" => Some(Command::Cache(CacheAction::List)),
+            "new" => Some(Command::New),
+            "watch" => Some(Command::Watch),
+            "tui" => Some(Command::Tui),
+            _ => None
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BenchArgs {
+    pub runs: usize,
+    pub warmup: usize,
+    pub baseline: Option<String>,
+    pub save_baseline: Option<String>,
+    pub threshold: f64
+}
+
+impl Default for BenchArgs {
+    fn default() -> Self {
+        BenchArgs { runs: 10, warmup: 2, baseline: None, save_baseline: None, threshold: 10.0 }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct VerifyArgs {
+    pub answers_file: String,
+    pub accept: bool
+}
+
+impl Default for VerifyArgs {
+    fn default() -> Self {
+        VerifyArgs { answers_file: DEFAULT_ANSWERS_FILE.to_string(), accept: false }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct SubmitArgs {
+    pub log_file: String
+}
+
+impl Default for SubmitArgs {
+    fn default() -> Self {
+        SubmitArgs { log_file: DEFAULT_SUBMISSIONS_FILE.to_string() }
+    }
+}
+
+#[derive(Debug, PartialEq, Default)]
+pub struct ExamplesArgs {
+    pub page: Option<String>,
+    pub force: bool
+}
+
+#[derive(Debug, PartialEq, Default)]
+pub struct NewArgs {
+    pub fetch: bool
+}
+
+#[derive(Debug, PartialEq)]
+pub struct WatchArgs {
+    pub interval_ms: u64,
+    pub once: bool
+}
+
+impl Default for WatchArgs {
+    fn default() -> Self {
+        WatchArgs { interval_ms: 500, once: false }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Args {
+    pub command: Command,
+    /// `None` means every day.
+    pub days: Option<Vec<i8>>,
+    /// `None` means both parts.
+    pub part: Option<i8>,
+    pub input: Option<String>,
+    pub example: bool,
+    pub year: i16,
+    pub quiet: bool,
+    pub list: bool,
+    pub help: bool,
+    pub format: OutputFormat,
+    /// `None` runs the days one after the other.
+    pub jobs: Option<usize>,
+    /// `None` lets the parts run as long as they need.
+    pub timeout: Option<Duration>,
+    pub bench: BenchArgs,
+    pub verify: VerifyArgs,
+    pub submit: SubmitArgs,
+    pub examples: ExamplesArgs,
+    pub new: NewArgs,
+    pub watch: WatchArgs
+}
+
+impl Default for Args {
+    fn default() -> Self {
+        Args {
+            command: Command::Run,
+            days: None,
+            part: None,
+            input: None,
+            example: false,
+            year: DEFAULT_YEAR,
+            quiet: false,
+            list: false,
+            help: false,
+            format: OutputFormat::Text,
+            jobs: None,
+            timeout: None,
+            bench: BenchArgs::default(),
+            verify: VerifyArgs::default(),
+            submit: SubmitArgs::default(),
+            examples: ExamplesArgs::default(),
+            new: NewArgs::default(),
+            watch: WatchArgs::default()
+        }
+    }
+}
+
+fn parse_day(day_str: &str) -> Result<i8, CliError> {
+    match day_str.trim().parse::<i8>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(CliError::new(format!("You didn't pass a correct day: {day_str}. A day goes from 1 to 25.")))
+    }
+}
+
+/// Parses a list of days and ranges like `1,3,5-8` into a sorted list without duplicates.
+pub fn parse_days(value: &str) -> Result<Vec<i8>, CliError> {
+    let mut days: Vec<i8> = vec![];
+    for item in value.split(',').filter(|item| !item.trim().is_empty()) {
+        if let Some((start_str, end_str)) = item.split_once('-') {
+            let start = parse_day(start_str)?;
+            let end = parse_day(end_str)?;
+            if end < start {
+                return Err(CliError::new(format!("The range {item} is reversed.")));
+            }
+            days.extend(start..=end);
+        } else {
+            days.push(parse_day(item)?);
+        }
+    }
+
+    if days.is_empty() {
+        return Err(CliError::new(format!("You didn't pass any day in: {value}.")));
+    }
+
+    days.sort();
+    days.dedup();
+    Ok(days)
+}
+
+fn parse_part(part_str: &str) -> Result<i8, CliError> {
+    match part_str.parse::<i8>() {
+        Ok(part) if part == 1 || part == 2 => Ok(part),
+        _ => Err(CliError::new(format!("The part must be 1 or 2, not {part_str}.")))
+    }
+}
+
+fn parse_year(year_str: &str) -> Result<i16, CliError> {
+    match year_str.parse::<i16>() {
+        Ok(year) if 2015 <= year => Ok(year),
+        _ => Err(CliError::new(format!("You didn't pass a correct year: {year_str}.")))
+    }
+}
+
+fn parse_format(format_str: &str) -> Result<OutputFormat, CliError> {
+    OutputFormat::from_word(format_str)
+        .ok_or_else(|| CliError::new(format!("The format must be text, json, markdown or junit, not {format_str}.")))
+}
+
+fn parse_timeout(timeout_str: &str) -> Result<Duration, CliError> {
+    let error = || CliError::new(format!("The timeout must be a positive number of seconds, not {timeout_str}."));
+    match timeout_str.parse::<f64>() {
+        Ok(secs) if secs > 0.0 => Duration::try_from_secs_f64(secs).map_err(|_| error()),
+        _ => Err(error())
+    }
+}
+
+fn parse_threshold(threshold_str: &str) -> Result<f64, CliError> {
+    match threshold_str.parse::<f64>() {
+        Ok(threshold) if threshold.is_finite() && threshold >= 0.0 => Ok(threshold),
+        _ => Err(CliError::new(format!("The threshold must be a percentage of at least 0, not {threshold_str}.")))
+    }
+}
+
+/// More threads than that would only wait for the cores.
+fn max_jobs() -> usize {
+    thread::available_parallelism().map_or(1, NonZeroUsize::get) * 4
+}
+
+fn parse_number<T: std::str::FromStr>(name: &str, value: &str) -> Result<T, CliError> {
+    value.parse::<T>()
+        .map_err(|_| CliError::new(format!("The option {name} needs a number, not {value}.")))
+}
+
+/// Parses the command line arguments, without the program name.
+pub fn parse_args(raw_args: &[String]) -> Result<Args, CliError> {
+    let mut args = Args::default();
+    let mut positionals: Vec<&str> = vec![];
+
+    let mut it = raw_args.iter();
+    while let Some(arg) = it.next() {
+        // Accept both `--part 1` and `--part=1`
+        let (flag, inline_value) = match arg.split_once('=') {
+            Some((flag, value)) if arg.starts_with("--") => (flag, Some(value.to_string())),
+            _ => (arg.as_str(), None)
+        };
+        let mut value_for = |name: &str| -> Result<String, CliError> {
+            if let Some(value) = inline_value.clone() {
+                return Ok(value);
+            }
+            it.next()
+                .cloned()
+                .ok_or_else(|| CliError::new(format!("The option {name} needs a value.")))
+        };
+
+        match flag {
+            "-h" | "--help" => args.help = true,
+            "-q" | "--quiet" => args.quiet = true,
+            "-l" | "--list" => args.list = true,
+            "-e" | "--example" => args.example = true,
+            "-p" | "--part" => args.part = Some(parse_part(&value_for(flag)?)?),
+            "-i" | "--input" => args.input = Some(value_for(flag)?),
+            "-y" | "--year" => args.year = parse_year(&value_for(flag)?)?,
+            "-j" | "--jobs" => args.jobs = Some(parse_number(flag, &value_for(flag)?)?),
+            "-t" | "--timeout" => args.timeout = Some(parse_timeout(&value_for(flag)?)?),
+            "-f" | "--format" => args.format = parse_format(&value_for(flag)?)?,
+            "--runs" => args.bench.runs = parse_number(flag, &value_for(flag)?)?,
+            "--warmup" => args.bench.warmup = parse_number(flag, &value_for(flag)?)?,
+            "--baseline" => args.bench.baseline = Some(value_for(flag)?),
+            "--save-baseline" => args.bench.save_baseline = Some(value_for(flag)?),
+            "--threshold" => args.bench.threshold = parse_threshold(&value_for(flag)?)?,
+            "--answers" => args.verify.answers_file = value_for(flag)?,
+            "--accept" => args.verify.accept = true,
+            "--log" => args.submit.log_file = value_for(flag)?,
+            "--page" => args.examples.page = Some(value_for(flag)?),
+            "--force" => args.examples.force = true,
+            "--fetch" => args.new.fetch = true,
+            "--interval" => args.watch.interval_ms = parse_number(flag, &value_for(flag)?)?,
+            "--once" => args.watch.once = true,
+            other if other.starts_with('-') && other.len() > 1 => {
+                return Err(CliError::new(format!("Unknown option: {other}.")));
+            },
+            other => positionals.push(other)
+        }
+    }
+
+    if let Some(command) = positionals.first().and_then(|word| Command::from_word(word)) {
+        args.command = command;
+        positionals.remove(0);
+    }
+    if let Command::Cache(_) = args.command {
+        if let Some(action) = positionals.first().and_then(|word| CacheAction::from_word(word)) {
+            args.command = Command::Cache(action);
+            positionals.remove(0);
+        }
+    }
+
+    match positionals.as_slice() {
+        [] => {},
+        [days] => args.days = Some(parse_days(days)?),
+        [days, part] => {
+            args.days = Some(parse_days(days)?);
+            let positional_part = parse_part(part)?;
+            if args.part.is_some_and(|part| part != positional_part) {
+                return Err(CliError::new(format!("The part {part} conflicts with --part.")));
+            }
+            args.part = Some(positional_part);
+        },
+        _ => return Err(CliError::new(format!("Too many arguments: {}.", positionals.join(" "))))
+    }
+
+    if args.command == Command::Submit && (args.days.as_ref().is_none_or(|days| days.len() != 1) || args.part.is_none()) {
+        return Err(CliError::new("submit needs a single day and a part.".to_string()));
+    }
+    if args.command == Command::New && args.days.as_ref().is_none_or(|days| days.len() != 1) {
+        return Err(CliError::new("new needs a single day.".to_string()));
+    }
+    if args.command == Command::Watch && args.days.as_ref().is_none_or(|days| days.len() != 1) {
+        return Err(CliError::new("watch needs a single day.".to_string()));
+    }
+    if args.command == Command::Tui && (args.days.is_some() || args.part.is_some()) {
+        return Err(CliError::new("tui shows the whole calendar, it takes no day nor part.".to_string()));
+    }
+    if args.watch.interval_ms == 0 {
+        return Err(CliError::new("--interval must be at least 1.".to_string()));
+    }
+    if args.examples.page.is_some() && args.days.as_ref().is_none_or(|days| days.len() != 1) {
+        return Err(CliError::new("--page can only be used with a single day.".to_string()));
+    }
+    if args.bench.runs == 0 {
+        return Err(CliError::new("--runs must be at least 1.".to_string()));
+    }
+    if args.jobs == Some(0) {
+        return Err(CliError::new("--jobs must be at least 1.".to_string()));
+    }
+    if args.jobs.is_some_and(|jobs| jobs > max_jobs()) {
+        return Err(CliError::new(format!("--jobs must be at most {}, 4 times the number of cores.", max_jobs())));
+    }
+    if args.jobs.is_some() && args.command != Command::Run {
+        return Err(CliError::new("--jobs can only be used with run.".to_string()));
+    }
+    if args.timeout.is_some() && !matches!(args.command, Command::Run | Command::Verify | Command::Submit | Command::Watch | Command::Tui) {
+        return Err(CliError::new("--timeout can only be used with run, verify, submit, watch and tui.".to_string()));
+    }
+    if args.format != OutputFormat::Text {
+        if args.command != Command::Run {
+            return Err(CliError::new("--format can only be used with run.".to_string()));
+        }
+        // Only the report goes to the standard output
+        args.quiet = true;
+    }
+    if args.input.is_some() && args.example {
+        return Err(CliError::new("--input and --example can't be used together.".to_string()));
+    }
+    if args.input.is_some() && args.days.as_ref().is_none_or(|days| days.len() != 1) {
+        return Err(CliError::new("--input can only be used with a single day.".to_string()));
+    }
+
+    Ok(args)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::cli::{max_jobs, parse_args, parse_days, Args, CacheAction, Command};
+    use crate::report::OutputFormat;
+
+    fn to_args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn it_test_parse_days_list_and_ranges() {
+        let result = parse_days("1,3,5-8").expect("There should be a result");
+
+        assert_eq!(result, vec![1, 3, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn it_test_parse_days_dedup() {
+        let result = parse_days("4,2-4,2").expect("There should be a result");
+
+        assert_eq!(result, vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn it_test_parse_days_invalid() {
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("8-5").is_err());
+        assert!(parse_days("a").is_err());
+        assert!(parse_days(",").is_err());
+    }
+
+    #[test]
+    fn it_test_no_args() {
+        let result = parse_args(&[]).expect("There should be a result");
+
+        assert_eq!(result, Args::default());
+    }
+
+    #[test]
+    fn it_test_legacy_positionals() {
+        let result = parse_args(&to_args("4 2")).expect("There should be a result");
+
+        assert_eq!(result.days, Some(vec![4]));
+        assert_eq!(result.part, Some(2));
+    }
+
+    #[test]
+    fn it_test_flags() {
+        let result = parse_args(&to_args("1-3 --part=1 -q -y 2025 --example --list")).expect("There should be a result");
+
+        assert_eq!(result.days, Some(vec![1, 2, 3]));
+        assert_eq!(result.part, Some(1));
+        assert!(result.quiet);
+        assert!(result.example);
+        assert!(result.list);
+        assert_eq!(result.year, 2025);
+    }
+
+    #[test]
+    fn it_test_format() {
+        let result = parse_args(&to_args("run 1-3 --format junit")).expect("There should be a result");
+
+        assert_eq!(result.format, OutputFormat::Junit);
+        assert!(result.quiet);
+        assert_eq!(parse_args(&to_args("-f md")).expect("There should be a result").format, OutputFormat::Markdown);
+        assert!(parse_args(&to_args("--format xml")).is_err());
+        assert!(parse_args(&to_args("verify --format json")).is_err());
+    }
+
+    #[test]
+    fn it_test_jobs() {
+        assert_eq!(parse_args(&to_args("-j 4")).expect("There should be a result").jobs, Some(4));
+        assert_eq!(parse_args(&to_args("1-3")).expect("There should be a result").jobs, None);
+        assert!(parse_args(&to_args("--jobs 0")).is_err());
+        assert!(parse_args(&to_args("--jobs 99999999999")).is_err());
+        assert!(parse_args(&to_args(&format!("--jobs {}", max_jobs()))).is_ok());
+        assert!(parse_args(&to_args("bench --jobs=2")).is_err());
+    }
+
+    #[test]
+    fn it_test_timeout() {
+        assert_eq!(parse_args(&to_args("verify -t 1.5")).expect("There should be a result").timeout, Some(Duration::from_millis(1500)));
+        assert_eq!(parse_args(&to_args("--timeout=30")).expect("There should be a result").timeout, Some(Duration::from_secs(30)));
+        assert!(parse_args(&to_args("--timeout 0")).is_err());
+        assert!(parse_args(&to_args("--timeout soon")).is_err());
+        assert!(parse_args(&to_args("--timeout 1e20")).is_err());
+        assert!(parse_args(&to_args("--timeout inf")).is_err());
+        assert!(parse_args(&to_args("bench --timeout 1")).is_err());
+    }
+
+    #[test]
+    fn it_test_bench_command() {
+        let result = parse_args(&to_args("bench 8 --runs 5 --warmup=0 --baseline base.json --threshold 2.5")).expect("There should be a result");
+
+        assert_eq!(result.command, Command::Bench);
+        assert_eq!(result.days, Some(vec![8]));
+        assert_eq!(result.bench.runs, 5);
+        assert_eq!(result.bench.warmup, 0);
+        assert_eq!(result.bench.baseline, Some("base.json".to_string()));
+        assert_eq!(result.bench.threshold, 2.5);
+        assert_eq!(parse_args(&to_args("bench --threshold 0")).expect("There should be a result").bench.threshold, 0.0);
+        for threshold in ["nan", "-5", "inf", "ten"] {
+            assert!(parse_args(&to_args(&format!("bench --threshold {threshold}"))).is_err());
+        }
+    }
+
+    #[test]
+    fn it_test_verify_command() {
+        let result = parse_args(&to_args("verify 1-2 --answers=my_answers.json --accept")).expect("There should be a result");
+
+        assert_eq!(result.command, Command::Verify);
+        assert_eq!(result.days, Some(vec![1, 2]));
+        assert_eq!(result.verify.answers_file, "my_answers.json");
+        assert!(result.verify.accept);
+    }
+
+    #[test]
+    fn it_test_submit_command() {
+        let result = parse_args(&to_args("submit 4 2 --log my_log.json")).expect("There should be a result");
+
+        assert_eq!(result.command, Command::Submit);
+        assert_eq!(result.days, Some(vec![4]));
+        assert_eq!(result.part, Some(2));
+        assert_eq!(result.submit.log_file, "my_log.json");
+    }
+
+    #[test]
+    fn it_test_session_commands() {
+        assert_eq!(parse_args(&to_args("login")).expect("There should be a result").command, Command::Login);
+        assert_eq!(parse_args(&to_args("whoami -y 2024")).expect("There should be a result").command, Command::Whoami);
+    }
+
+    #[test]
+    fn it_test_examples_command() {
+        let result = parse_args(&to_args("examples 7 --page day7.html --force")).expect("There should be a result");
+
+        assert_eq!(result.command, Command::Examples);
+        assert_eq!(result.examples.page, Some("day7.html".to_string()));
+        assert!(result.examples.force);
+        assert!(parse_args(&to_args("examples --page day7.html")).is_err());
+    }
+
+    #[test]
+    fn it_test_new_command() {
+        let result = parse_args(&to_args("new 13 -y 2024 --fetch")).expect("There should be a result");
+
+        assert_eq!(result.command, Command::New);
+        assert_eq!(result.days, Some(vec![13]));
+        assert_eq!(result.year, 2024);
+        assert!(result.new.fetch);
+        assert!(parse_args(&to_args("new")).is_err());
+        assert!(parse_args(&to_args("new 1-2")).is_err());
+    }
+
+    #[test]
+    fn it_test_watch_command() {
+        let result = parse_args(&to_args("watch 4 2 --interval=200")).expect("There should be a result");
+
+        assert_eq!(result.command, Command::Watch);
+        assert_eq!(result.days, Some(vec![4]));
+        assert_eq!(result.part, Some(2));
+        assert_eq!(result.watch.interval_ms, 200);
+        assert!(!result.watch.once);
+        assert!(parse_args(&to_args("watch 4 --once")).expect("There should be a result").watch.once);
+        assert!(parse_args(&to_args("watch 4-5")).is_err());
+        assert!(parse_args(&to_args("watch 4 --interval 0")).is_err());
+    }
+
+    #[test]
+    fn it_test_runs_solvers() {
+        assert!(Command::Run.runs_solvers());
+        assert!(Command::Watch.runs_solvers());
+        assert!(!Command::Whoami.runs_solvers());
+        assert!(!Command::Cache(CacheAction::List).runs_solvers());
+        assert!(!Command::New.runs_solvers());
+    }
+
+    #[test]
+    fn it_test_tui_command() {
+        let result = parse_args(&to_args("tui -y 2024 --answers=my_answers.json -t 2")).expect("There should be a result");
+
+        assert_eq!(result.command, Command::Tui);
+        assert_eq!(result.year, 2024);
+        assert_eq!(result.verify.answers_file, "my_answers.json");
+        assert!(parse_args(&to_args("tui 4")).is_err());
+        assert!(parse_args(&to_args("tui --part 1")).is_err());
+    }
+
+    #[test]
+    fn it_test_cache_command() {
+        assert_eq!(parse_args(&to_args("cache")).expect("There should be a result").command, Command::Cache(CacheAction::List));
+
+        let result = parse_args(&to_args("cache refresh 3-4 -y 2024")).expect("There should be a result");
+        assert_eq!(result.command, Command::Cache(CacheAction::Refresh));
+        assert_eq!(result.days, Some(vec![3, 4]));
+        assert_eq!(result.year, 2024);
+    }
+
+    #[test]
+    fn it_test_invalid_args() {
+        assert!(parse_args(&to_args("4 3")).is_err());
+        assert!(parse_args(&to_args("4 --part")).is_err());
+        assert!(parse_args(&to_args("4 1 --part 2")).is_err());
+        assert!(parse_args(&to_args("--unknown")).is_err());
+        assert!(parse_args(&to_args("1 2 3")).is_err());
+        assert!(parse_args(&to_args("1-2 --input my_input.txt")).is_err());
+        assert!(parse_args(&to_args("1 --input my_input.txt --example")).is_err());
+        assert!(parse_args(&to_args("bench --runs 0")).is_err());
+        assert!(parse_args(&to_args("bench --runs many")).is_err());
+        assert!(parse_args(&to_args("submit 4")).is_err());
+        assert!(parse_args(&to_args("submit 4-5 --part 1")).is_err());
+        assert!(parse_args(&to_args("cache empty")).is_err());
+    }
+}
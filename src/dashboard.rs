@@ -0,0 +1,290 @@
+//! The state of the `tui` dashboard, kept apart from the terminal so it can be tested.
+
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::answers::{AnswerBook, Verdict};
+use crate::day_factory;
+use crate::runner::error_chain;
+use crate::utils::answer::Answer;
+use crate::utils::day::Solver;
+use crate::utils::day_error::DayError;
+use crate::utils::examples::Example;
+use crate::utils::guard;
+
+/// The number of puzzles of the calendar, 12 since 2025.
+pub fn calendar_days(year: i16) -> i8 {
+    if year >= 2025 { 12 } else { 25 }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PartStatus {
+    /// No solver is registered, or the part is not solved yet.
+    NotImplemented,
+    /// Registered, but never run and without a known answer.
+    Pending,
+    /// The answer book has the answer, the part wasn't run yet.
+    AnswerKnown,
+    /// Run, but there is no known answer to check it against.
+    Answered,
+    Verified,
+    /// Run with an error, or an answer that is not the known one.
+    Failing
+}
+
+impl fmt::Display for PartStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            PartStatus::NotImplemented => "not implemented",
+            PartStatus::Pending => "pending",
+            PartStatus::AnswerKnown => "answer known",
+            PartStatus::Answered => "answered",
+            PartStatus::Verified => "verified",
+            PartStatus::Failing => "failing"
+        };
+        write!(f, "{text}")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartState {
+    pub status: PartStatus,
+    /// The answer of the last run, or its error.
+    pub detail: Option<String>,
+    pub elapsed: Option<Duration>
+}
+
+/// A day of the calendar, registered or not.
+pub struct DayRow {
+    pub day: i8,
+    pub title: &'static str,
+    pub solver: Option<&'static dyn Solver>,
+    pub parts: [PartState; 2],
+    /// How many examples passed, out of how many, on the last run of the examples.
+    pub examples: Option<(usize, usize)>
+}
+
+impl DayRow {
+    /// The time of the last run of the parts.
+    pub fn last_run_time(&self) -> Option<Duration> {
+        let times: Vec<Duration> = self.parts.iter().filter_map(|part| part.elapsed).collect();
+        (!times.is_empty()).then(|| times.iter().sum())
+    }
+}
+
+/// The state of the dashboard: the days of the calendar and the selected one.
+pub struct Dashboard {
+    pub year: i16,
+    pub rows: Vec<DayRow>,
+    pub selected: usize,
+    pub book: AnswerBook,
+    /// What the last action did, shown at the bottom.
+    pub message: String
+}
+
+impl Dashboard {
+    pub fn new(year: i16, book: AnswerBook) -> Dashboard {
+        let rows = (1..=calendar_days(year))
+            .map(|day| {
+                let entry = day_factory::find_day(year, day);
+                let part = |part: i8| {
+                    let status = match (entry, book.get(year, day, part)) {
+                        (None, _) => PartStatus::NotImplemented,
+                        (Some(_), Some(_)) => PartStatus::AnswerKnown,
+                        (Some(_), None) => PartStatus::Pending
+                    };
+                    PartState { status, detail: book.get(year, day, part).map(str::to_string), elapsed: None }
+                };
+                DayRow {
+                    day,
+                    title: entry.map_or("", |entry| entry.title),
+                    solver: entry.map(|entry| entry.solver),
+                    parts: [part(1), part(2)],
+                    examples: None
+                }
+            })
+            .collect();
+        Dashboard { year, rows, selected: 0, book, message: String::new() }
+    }
+
+    pub fn selected_row(&self) -> &DayRow {
+        &self.rows[self.selected]
+    }
+
+    pub fn select_next(&mut self) {
+        self.selected = (self.selected + 1).min(self.rows.len() - 1);
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Updates the status of a part after a run on the real input.
+    pub fn record_run(&mut self, day: i8, part: i8, result: &Result<Answer, DayError>, elapsed: Duration) {
+        let (status, detail) = match result {
+            Ok(answer) => match self.book.check(self.year, day, part, answer) {
+                Verdict::Pass => (PartStatus::Verified, answer.to_string()),
+                Verdict::Fail { expected } => (PartStatus::Failing, format!("{answer}, expected {expected}")),
+                Verdict::Unknown => (PartStatus::Answered, answer.to_string())
+            },
+            Err(DayError::Unsolved { .. }) => (PartStatus::NotImplemented, "not solved yet".to_string()),
+            Err(error) => (PartStatus::Failing, error_chain(error))
+        };
+        self.set_part(day, part, PartState { status, detail: Some(detail), elapsed: Some(elapsed) });
+    }
+
+    fn set_part(&mut self, day: i8, part: i8, state: PartState) {
+        if let Some(row) = self.rows.iter_mut().find(|row| row.day == day) {
+            row.parts[(part - 1) as usize] = state;
+        }
+    }
+
+    pub fn record_examples(&mut self, day: i8, passed: usize, total: usize) {
+        if let Some(row) = self.rows.iter_mut().find(|row| row.day == day) {
+            row.examples = Some((passed, total));
+        }
+    }
+
+    /// Runs both parts of the selected day on its input. A parsing error fails both parts.
+    pub fn run_selected(&mut self, input: &str, timeout: Option<Duration>) {
+        let row = self.selected_row();
+        let (day_nb, Some(day)) = (row.day, row.solver) else {
+            self.message = format!("The day{} doesn't exist yet", row.day);
+            return;
+        };
+
+        let start = Instant::now();
+        let parsed = match guard::parse_guarded(day, input) {
+            Ok(parsed) => parsed,
+            Err(error) => {
+                let message = format!("Error while parsing the input of day {day_nb}: {}", error_chain(&error));
+                let state = PartState { status: PartStatus::Failing, detail: Some(message.clone()), elapsed: Some(start.elapsed()) };
+                for part in [1, 2] {
+                    self.set_part(day_nb, part, state.clone());
+                }
+                self.message = message;
+                return;
+            }
+        };
+        let parse_time = start.elapsed();
+        for part in [1, 2] {
+            let start = Instant::now();
+            let result = guard::run_part_guarded(day, part, &parsed, timeout);
+            self.record_run(day_nb, part, &result, start.elapsed());
+        }
+        self.message = format!("Ran day{day_nb}, input parsed in {parse_time:.2?}");
+    }
+
+    /// Runs the selected day on the examples that have an expected answer.
+    pub fn run_selected_examples(&mut self, examples: &[(String, Example)], timeout: Option<Duration>) {
+        let row = self.selected_row();
+        let (day_nb, Some(day)) = (row.day, row.solver) else {
+            self.message = format!("The day{} doesn't exist yet", row.day);
+            return;
+        };
+
+        let (mut passed, mut total) = (0, 0);
+        for (_, example) in examples {
+            let parsed = guard::parse_guarded(day, &example.input);
+            for (part, expected) in [(1, &example.part1), (2, &example.part2)] {
+                let Some(expected) = expected else { continue };
+                total += 1;
+                let answer = parsed.as_ref().ok()
+                    .and_then(|parsed| guard::run_part_guarded(day, part, parsed, timeout).ok());
+                if answer.is_some_and(|answer| answer.to_string() == *expected) {
+                    passed += 1;
+                }
+            }
+        }
+        self.record_examples(day_nb, passed, total);
+        self.message = format!("{passed}/{total} example answer(s) of day{day_nb} passed");
+    }
+
+    /// The longest last run time, the full width of the timing bars.
+    pub fn max_run_time(&self) -> Option<Duration> {
+        self.rows.iter().filter_map(DayRow::last_run_time).max()
+    }
+}
+
+/// A bar of `width` cells, as long as `elapsed` compared to `max`. Any run gets at least one cell.
+pub fn timing_bar(elapsed: Duration, max: Duration, width: usize) -> String {
+    if max.is_zero() {
+        return "█".to_string();
+    }
+    let cells = (elapsed.as_secs_f64() / max.as_secs_f64() * width as f64).ceil() as usize;
+    "█".repeat(cells.clamp(1, width))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::answers::AnswerBook;
+    use crate::dashboard::{calendar_days, timing_bar, Dashboard, PartStatus};
+    use crate::utils::answer::Answer;
+    use crate::utils::day_error::DayError;
+    use crate::utils::examples::Example;
+
+    const DAY1_EXAMPLE: &str = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
+
+    #[test]
+    fn it_test_statuses() {
+        let mut book = AnswerBook::default();
+        book.record(2025, 4, 1, &Answer::from(1564_i64));
+        book.record(2025, 4, 2, &Answer::from(9401_i64));
+        let mut dashboard = Dashboard::new(2025, book);
+
+        assert_eq!(dashboard.rows.len(), calendar_days(2025) as usize);
+        assert_eq!(dashboard.rows[3].parts[0].status, PartStatus::AnswerKnown);
+        assert_eq!(dashboard.rows[0].parts[0].status, PartStatus::Pending);
+
+        dashboard.record_run(4, 1, &Ok(Answer::from(1564_i64)), Duration::from_millis(2));
+        dashboard.record_run(4, 2, &Ok(Answer::from(42_i64)), Duration::from_millis(40));
+        dashboard.record_run(10, 2, &Err(DayError::Unsolved { part: 2 }), Duration::ZERO);
+        dashboard.record_run(1, 1, &Ok(Answer::from(1147_i64)), Duration::from_millis(1));
+
+        assert_eq!(dashboard.rows[3].parts[0].status, PartStatus::Verified);
+        assert_eq!(dashboard.rows[3].parts[1].status, PartStatus::Failing);
+        assert_eq!(dashboard.rows[3].parts[1].detail.as_deref(), Some("42, expected 9401"));
+        assert_eq!(dashboard.rows[9].parts[1].status, PartStatus::NotImplemented);
+        assert_eq!(dashboard.rows[0].parts[0].status, PartStatus::Answered);
+        assert_eq!(dashboard.max_run_time(), Some(Duration::from_millis(42)));
+    }
+
+    #[test]
+    fn it_test_selection() {
+        let mut dashboard = Dashboard::new(2025, AnswerBook::default());
+        dashboard.select_previous();
+        assert_eq!(dashboard.selected_row().day, 1);
+
+        for _ in 0..20 {
+            dashboard.select_next();
+        }
+        assert_eq!(dashboard.selected_row().day, 12);
+    }
+
+    #[test]
+    fn it_test_run_selected() {
+        let mut book = AnswerBook::default();
+        book.record(2025, 1, 1, &Answer::from(3_i64));
+        let mut dashboard = Dashboard::new(2025, book);
+
+        dashboard.run_selected(DAY1_EXAMPLE, None);
+        assert_eq!(dashboard.rows[0].parts[0].status, PartStatus::Verified);
+        assert_eq!(dashboard.rows[0].parts[1].status, PartStatus::Answered);
+        assert_eq!(dashboard.rows[0].parts[1].detail.as_deref(), Some("6"));
+        assert!(dashboard.rows[0].last_run_time().is_some());
+
+        let example = Example { input: DAY1_EXAMPLE.to_string(), part1: Some("3".to_string()), part2: Some("7".to_string()) };
+        dashboard.run_selected_examples(&[("day1_1.txt".to_string(), example)], None);
+        assert_eq!(dashboard.rows[0].examples, Some((1, 2)));
+    }
+
+    #[test]
+    fn it_test_timing_bar() {
+        assert_eq!(timing_bar(Duration::from_millis(50), Duration::from_millis(100), 10), "█████");
+        assert_eq!(timing_bar(Duration::from_nanos(1), Duration::from_secs(1), 10), "█");
+        assert_eq!(timing_bar(Duration::from_secs(2), Duration::from_secs(1), 10).chars().count(), 10);
+        assert_eq!(calendar_days(2024), 25);
+    }
+}
@@ -1,32 +1,77 @@
-use crate::days;
-use crate::utils;
-
-pub fn create_day(day: i8) -> Option<&'static dyn utils::day::Day> {
-    if day == 1 {
-        return Some(&days::day1::Day1);
-    } else if day == 2 {
-        return Some(&days::day2::Day2);
-    } else if day == 3 {
-        return Some(&days::day3::Day3);
-    } else if day == 4 {
-        return Some(&days::day4::Day4);
-    } else if day == 5 {
-        return Some(&days::day5::Day5);
-    } else if day == 6 {
-        return Some(&days::day6::Day6);
-    } else if day == 7 {
-        return Some(&days::day7::Day7);
-    } else if day == 8 {
-        return Some(&days::day8::Day8);
-    } else if day == 9 {
-        return Some(&days::day9::Day9);
-    } else if day == 10 {
-        return Some(&days::day10::Day10);
-    } else if day == 11 {
-        return Some(&days::day11::Day11);
-    } else if day == 12 {
-        return Some(&days::day12::Day12);
+use crate::utils::day::Solver;
+
+/// A registered day, with its solver and some metadata to list it.
+pub struct DayEntry {
+    pub year: i16,
+    pub title: &'static str,
+    pub tags: &'static [&'static str],
+    pub solver: &'static dyn Solver
+}
+
+impl DayEntry {
+    pub fn day(&self) -> i8 {
+        self.solver.get_nb()
+    }
+}
+
+inventory::collect!(DayEntry);
+
+/// Registers a day in the factory. To call once, next to the `Day` implementation:
+/// `register_day!(Day1, year: 2025, title: "Secret Entrance", tags: ["simulation"]);`
+macro_rules! register_day {
+    ($solver:expr, year: $year:expr, title: $title:expr, tags: [$($tag:expr),* $(,)?]) => {
+        inventory::submit! {
+            $crate::day_factory::DayEntry { year: $year, title: $title, tags: &[$($tag),*], solver: &$solver }
+        }
+    };
+}
+pub(crate) use register_day;
+
+/// Every registered day, sorted by year then day.
+pub fn all_days() -> Vec<&'static DayEntry> {
+    let mut entries: Vec<&'static DayEntry> = inventory::iter::<DayEntry>.into_iter().collect();
+    entries.sort_by_key(|entry| (entry.year, entry.day()));
+    entries
+}
+
+/// Every registered day of the given year, sorted by day.
+pub fn days_of_year(year: i16) -> Vec<&'static DayEntry> {
+    all_days().into_iter()
+        .filter(|entry| entry.year == year)
+        .collect()
+}
+
+pub fn find_day(year: i16, day: i8) -> Option<&'static DayEntry> {
+    inventory::iter::<DayEntry>.into_iter()
+        .find(|entry| entry.year == year && entry.day() == day)
+}
+
+pub fn create_day(year: i16, day: i8) -> Option<&'static dyn Solver> {
+    find_day(year, day).map(|entry| entry.solver)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day_factory::{all_days, create_day, days_of_year, find_day};
+
+    #[test]
+    fn it_test_days_are_unique_and_sorted() {
+        let keys: Vec<(i16, i8)> = all_days().iter().map(|entry| (entry.year, entry.day())).collect();
+        let mut expected = keys.clone();
+        expected.sort();
+        expected.dedup();
+
+        assert_eq!(keys, expected);
     }
 
-    None
-}
\ No newline at end of file
+    #[test]
+    fn it_test_lookup() {
+        let entry = find_day(2025, 4).expect("The day 4 should be registered");
+
+        assert_eq!(entry.day(), 4);
+        assert_eq!(entry.year, 2025);
+        assert!(create_day(2025, 26).is_none());
+        assert!(create_day(2014, 1).is_none());
+        assert_eq!(days_of_year(2025).len(), 12);
+    }
+}
@@ -0,0 +1,106 @@
+use crate::day_factory::register_day;
+use crate::utils::answer::Answer;
+use crate::utils::day::Day;
+use crate::utils::day_error::DayError;
+
+pub struct Day1;
+
+register_day!(Day1, year: 2025, title: "Secret Entrance", tags: ["simulation", "modular-arithmetic"]);
+
+/// Splits a rotation like `L68` into its direction and its value.
+fn parse_rotation(line_idx: usize, rotation: &str) -> Result<(char, i64), DayError> {
+    let direction = rotation.chars().next().unwrap_or_default();
+    if direction != 'L' && direction != 'R' {
+        return Err(DayError::parse(line_idx + 1, rotation, "The rotation should start with L or R").at_column(1));
+    }
+
+    let value = rotation[1..].parse::<i64>()
+        .map_err(|e| DayError::parse(line_idx + 1, rotation, "The rotation should end with a number").at_column(2).with_source(e))?;
+    Ok((direction, value))
+}
+
+impl Day for Day1 {
+    type Input = Vec<(char, i64)>;
+
+    fn get_nb(&self) -> i8 {
+        1
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, DayError> {
+        input.split("\n")
+            .enumerate()
+            .filter(|(_, rotation)| !rotation.is_empty())
+            .map(|(line_idx, rotation)| parse_rotation(line_idx, rotation))
+            .collect()
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer, DayError> {
+        log::debug!("Day 1 part 1");
+
+        let mut dial = 50;
+
+        let mut count_0: i64 = 0;
+        for &(direction, value) in input {
+            if direction == 'L' {
+                dial = (dial - value) % 100;
+            } else {
+                dial = (dial + value) % 100;
+            }
+
+            if dial == 0 {
+                count_0 += 1;
+            }
+        }
+
+        Ok(count_0.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer, DayError> {
+        log::debug!("Day 1 part 2");
+
+        let mut dial = 50;
+
+        let mut count_0: i64 = 0;
+        for &(direction, value) in input {
+            if direction == 'L' {
+                if 0 < dial && dial - value < 0 { 
+                    let should_add_one = (dial - value) % 100 != 0;
+                    count_0 += (dial - value).abs() / 100 + if should_add_one { 1 } else { 0 };
+                } else if dial <= 0 && dial - value < -100 {
+                    let should_sub_one = (dial - value) % 100 == 0;
+                    count_0 += (dial - value).abs() / 100 - if should_sub_one { 1 } else { 0 };
+                }
+                dial = (dial - value) % 100;
+            } else {
+                if dial < 0 && 0 < dial + value { 
+                    let should_add_one = (dial + value) % 100 != 0;
+                    count_0 += (dial + value) / 100 + if should_add_one { 1 } else { 0 };
+                } else if 0 <= dial && 100 < dial + value {
+                    let should_sub_one = (dial + value) % 100 == 0;
+                    count_0 += (dial + value) / 100 - if should_sub_one { 1 } else { 0 };
+                }
+                dial = (dial + value) % 100;
+            }
+
+            if dial == 0 {
+                count_0 += 1;
+            }
+        }
+
+        Ok(count_0.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::y2025::day1::Day1;
+    use crate::utils::day::Day;
+
+    #[test]
+    fn it_test_invalid_rotation() {
+        let test_input = "L68\nX30".to_string();
+        let error = Day1.solve_part1(&test_input).expect_err("There should be an error");
+
+        assert_eq!(error.to_string(), "Parse error at line 2, column 1: The rotation should start with L or R (in `X30`)");
+    }
+}
\ No newline at end of file
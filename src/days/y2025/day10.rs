@@ -0,0 +1,259 @@
+use std::collections::HashMap;
+
+use regex::Regex;
+
+use crate::day_factory::register_day;
+use crate::utils::answer::Answer;
+use crate::utils::day::Day;
+use crate::utils::day_error::DayError;
+
+pub struct Day10;
+
+register_day!(Day10, year: 2025, title: "Factory", tags: ["bitmask", "brute-force"]);
+
+#[derive(Debug)]
+struct Mask(u32);
+
+impl From<&str> for Mask {
+    fn from(value: &str) -> Self {
+        let mut res: u32 = 0;
+        for (i, c) in value.chars().enumerate() {
+            if c == '#' {
+                res |= 1 << i;
+            }
+        }
+
+        Mask(res)
+    }
+}
+
+#[derive(Debug)]
+struct Schema {
+    goal: Mask,
+    buttons: Vec<Mask>
+}
+
+/// Splits a line like `[.##.] (3) (1,3) {3,5,4,7}` into its lights, its buttons and its joltages.
+fn capture_line(line_idx: usize, value: &str) -> Result<(&str, Vec<Vec<u32>>, &str), DayError> {
+    let re = Regex::new(r"\[([.#]+)\]\ ([(),0-9 ]+)\ \{([0-9,]+)\}")
+        .expect("Should not have issue with regex");
+
+    let parsed = re.captures(value)
+        .ok_or_else(|| DayError::parse(line_idx + 1, value, "Should look like [.##.] (3) (1,3) {3,5,4,7}"))?;
+    let buttons_match = parsed.get(2).expect("The group 2 is not optional");
+    let buttons = buttons_match.as_str().split(' ')
+        .filter(|s| !s.is_empty())
+        .map(|s| s.trim_start_matches('(').trim_end_matches(')')
+            .split(',')
+            .map(|b| b.parse::<u32>())
+            .collect::<Result<Vec<u32>, _>>()
+            .map_err(|e| DayError::parse(line_idx + 1, value, format!("The button {s} should only have numbers"))
+                .at_column(buttons_match.start() + 1)
+                .with_source(e)
+            )
+        )
+        .collect::<Result<Vec<Vec<u32>>, DayError>>()?;
+
+    let lights = parsed.get(1).expect("The group 1 is not optional").as_str();
+    let joltages = parsed.get(3).expect("The group 3 is not optional").as_str();
+    Ok((lights, buttons, joltages))
+}
+
+impl Schema {
+    fn create_from(lights: &str, buttons: &[Vec<u32>]) -> Schema {
+        let goal = Mask::from(lights);
+        let buttons: Vec<Mask> = buttons.iter()
+            .map(|b| b.iter()
+                .map(|i| 1 << i)
+                .fold(0, |acc, i| acc | i)
+            ).map(Mask)
+            .collect();
+        Schema { goal, buttons }
+    }
+}
+
+impl Schema {
+    fn resolve(&self) -> usize {
+        log::trace!("Let's resolve: {:?}", self);
+
+        let mut min = self.buttons.len();
+        for i in 0..2_usize.pow(self.buttons.len() as u32) {
+            let mut cur_mask: u32 = 0;
+            let mut click = 0;
+            for j in 0..self.buttons.len() {
+                let v = (i >> j) & 1;
+                if v == 1 {
+                    click += 1;
+                    cur_mask ^= self.buttons[j].0;
+                }
+            }
+
+            if cur_mask == self.goal.0 && click < min {
+                min = click;
+            } 
+        }
+        min
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+struct BMask(u128);
+
+impl TryFrom<&str> for BMask {
+    type Error = std::num::ParseIntError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let m: u128 = value.split(',')
+            .map(|s| s.parse::<u8>())
+            .collect::<Result<Vec<u8>, _>>()?
+            .into_iter()
+            .enumerate()
+            .fold(0, |acc, (i, u)| acc | ((u as u128) << (i*8)));
+
+        Ok(BMask(m))
+    }
+}
+
+impl BMask {
+    fn keep_u8(&self, i: usize) -> u128 {
+        let mask = (u8::MAX as u128) << (i * 8);
+        self.0 & mask
+    }
+
+    fn is_greater_than(&self, o: &Self, len: usize) -> bool {
+        (0..len).any(|i| self.keep_u8(i) > o.keep_u8(i))
+    }
+}
+
+#[derive(Debug)]
+struct Schema2 {
+    goal: BMask,
+    buttons: Vec<BMask>,
+    len: usize
+}
+
+impl Schema2 {
+    fn create_from(line_idx: usize, value: &str, buttons: &[Vec<u32>], joltages: &str) -> Result<Schema2, DayError> {
+        let buttons: Vec<BMask> = buttons.iter()
+            .map(|b| b.iter()
+                .fold(0, |acc, u| acc | (1 << (u * 8)))
+            )
+            .map(BMask)
+            .collect();
+
+        let len = joltages.split(',').count();
+
+        let goal = BMask::try_from(joltages)
+            .map_err(|e| DayError::parse(line_idx + 1, value, "A joltage should fit in a u8").with_source(e))?;
+        Ok(Schema2 { goal, buttons, len })
+    }
+}
+
+impl Schema2 {
+    fn min_path(&self, res_map: &mut HashMap<(u128, u16), u16>, cur_b: &BMask, cur_goal: &BMask, it: u16, min_it: u16) -> u16 {
+        let new_it = it + 1;
+        if new_it >= min_it {
+            return u16::MAX;
+        }
+
+        let new_goal = BMask(cur_goal.0 + cur_b.0);
+        if new_goal.0 == self.goal.0 {
+            return new_it;
+        } else if let Some(res) = res_map.get(&(new_goal.0, new_it)) {
+            return *res;
+        } else if new_goal.is_greater_than(&self.goal, self.len) {
+            return u16::MAX;
+        }
+
+        let res = self.buttons.iter()
+            .fold(min_it, |acc, next_b| {
+                let new_min = self.min_path(res_map, next_b, &new_goal, new_it, acc);
+                acc.min(new_min)
+            });
+
+        res_map.insert((new_goal.0, new_it), res);
+
+        res
+    }
+
+    fn slow_resolve(&self) -> u16 {
+        let mut res_map: HashMap<(u128, u16), u16> = HashMap::new();
+        self.buttons.iter()
+            .fold(u16::MAX, |acc, next_b| {
+                let new_min = self.min_path(&mut res_map, next_b, &BMask(0),  0, acc);
+                acc.min(new_min)
+        })
+    }
+
+    fn resolve(&self) -> Result<u16, DayError> {
+        Err(DayError::Unsolved { part: 2 })
+    }
+
+}
+
+/// Both views of the machines, one schema per line of the input.
+pub struct Machines {
+    schemas: Vec<Schema>,
+    schemas2: Vec<Schema2>
+}
+
+impl Day10 {
+    #[allow(unused)]
+    fn slow_part2(&self, input: &Machines) -> Result<Answer, DayError> {
+        let result: u16 = input.schemas2.iter()
+            .map(Schema2::slow_resolve)
+            .sum();
+
+        Ok(u64::from(result).into())
+    }
+}
+
+impl Day for Day10 {
+    type Input = Machines;
+
+    fn get_nb(&self) -> i8 {
+        10
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, DayError> {
+        let mut schemas = vec![];
+        let mut schemas2 = vec![];
+        for (line_idx, line) in input.split('\n').enumerate().filter(|(_, s)| !s.trim().is_empty()) {
+            let (lights, buttons, joltages) = capture_line(line_idx, line)?;
+            schemas.push(Schema::create_from(lights, &buttons));
+            schemas2.push(Schema2::create_from(line_idx, line, &buttons, joltages)?);
+        }
+
+        Ok(Machines { schemas, schemas2 })
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer, DayError> {
+        let result: usize = input.schemas.iter()
+            .map(Schema::resolve)
+            .sum();
+
+        Ok(result.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer, DayError> {
+        let result: u16 = input.schemas2.iter()
+            .map(Schema2::resolve)
+            .sum::<Result<u16, DayError>>()?;
+
+        Ok(u64::from(result).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::y2025::day10::Day10;
+    use crate::utils::day::Day;
+
+    #[test]
+    fn it_test_invalid_line() {
+        let test_input = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}\n[...#.] (0,2,3,4) {7,5,12,7,2".to_string();
+        let error = Day10.solve_part1(&test_input).expect_err("There should be an error");
+
+        assert_eq!(error.to_string(), "Parse error at line 2: Should look like [.##.] (3) (1,3) {3,5,4,7} (in `[...#.] (0,2,3,4) {7,5,12,7,2`)");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,142 @@
+use std::collections::HashMap;
+
+use crate::day_factory::register_day;
+use crate::utils::answer::Answer;
+use crate::utils::day::Day;
+use crate::utils::day_error::DayError;
+
+pub struct Day11;
+
+register_day!(Day11, year: 2025, title: "Reactor", tags: ["graph", "memoization"]);
+
+#[derive(Debug, Clone)]
+pub struct Device {
+    id: String,
+    outputs: Vec<String>
+}
+
+impl Device {
+    fn create_from(line_idx: usize, value: &str) -> Result<Device, DayError> {
+        let (id, outputs_str) = value.split_once(": ")
+            .ok_or_else(|| DayError::parse(line_idx + 1, value, "Should have a name and outputs"))?;
+        let outputs: Vec<String> = outputs_str.split(' ').map(str::to_string).collect();
+
+        Ok(Device { id: id.to_string(), outputs })
+    }
+}
+
+/// Parses the devices, checking that every output leads to a known device or to `out`.
+fn create_devices(input: &str) -> Result<HashMap<String, Device>, DayError> {
+    let d_map: HashMap<String, Device> = input.split('\n')
+        .enumerate()
+        .filter(|(_, d)| !d.trim().is_empty())
+        .map(|(line_idx, d)| Device::create_from(line_idx, d))
+        .map(|d| d.map(|d| (d.id.clone(), d)))
+        .collect::<Result<HashMap<String, Device>, DayError>>()?;
+
+    let unknown_output = d_map.values()
+        .flat_map(|d| d.outputs.iter().map(move |o| (d, o)))
+        .find(|(_, o)| *o != "out" && !d_map.contains_key(*o));
+    if let Some((d, o)) = unknown_output {
+        return Err(DayError::solver(format!("The device {} outputs to an unknown device: {o}", d.id)));
+    }
+
+    Ok(d_map)
+}
+
+fn check_start(d_map: &HashMap<String, Device>, start: &str) -> Result<(), DayError> {
+    if !d_map.contains_key(start) {
+        return Err(DayError::solver(format!("There is no device {start} to start from")));
+    }
+    Ok(())
+}
+
+fn solve(d_map: &HashMap<String, Device>, d_str: &String, r_map: &mut HashMap<String, i64>) -> i64 {
+    if d_str == "out" {
+        return 1;
+    } else if let Some(res) = r_map.get(d_str) {
+        return *res;
+    }
+
+
+    let res = d_map[d_str].outputs
+        .iter()
+        .map(|next_d| solve(d_map, next_d, r_map))
+        .sum();
+
+    r_map.insert(d_str.clone(), res);
+    res
+}
+
+fn solve_v2(
+    d_map: &HashMap<String, Device>, 
+    d_str: &String, 
+    r_map: &mut HashMap<(bool, bool, String), i64>, 
+    f_fft: bool, 
+    f_dac: bool
+) -> i64 {
+    if d_str == "out" {
+        return if f_fft && f_dac { 1 } else { 0 };
+    } else if let Some(res) = r_map.get(&(f_fft, f_dac, d_str.clone())) {
+        return *res;
+    }
+
+    let next_fft = f_fft || d_str == "fft";
+    let next_dac = f_dac || d_str == "dac";
+
+    let res = d_map[d_str].outputs
+        .iter()
+        .map(|next_d| solve_v2(d_map, next_d, r_map, next_fft, next_dac))
+        .sum();
+
+    r_map.insert((next_fft, next_dac, d_str.clone()), res);
+    res
+}
+
+impl Day for Day11 {
+    type Input = HashMap<String, Device>;
+
+    fn get_nb(&self) -> i8 {
+        11
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, DayError> {
+        create_devices(input)
+    }
+
+    fn part1(&self, d_map: &Self::Input) -> Result<Answer, DayError> {
+        let s_d = "you".to_string();
+        check_start(d_map, &s_d)?;
+        let mut r_map: HashMap<String, i64> = HashMap::new();
+
+        let result = solve(d_map, &s_d, &mut r_map);
+
+        Ok(result.into())
+    }
+
+    fn part2(&self, d_map: &Self::Input) -> Result<Answer, DayError> {
+        let s_d = "svr".to_string();
+        check_start(d_map, &s_d)?;
+        let mut r_map: HashMap<(bool, bool, String), i64> = HashMap::new();
+
+        let result = solve_v2(d_map, &s_d, &mut r_map, false, false);
+
+        Ok(result.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::y2025::day11::Day11;
+    use crate::utils::day::Day;
+
+    #[test]
+    fn it_test_unknown_output() {
+        let test_input = 
+"you: bbb
+bbb: zzz out".to_string();
+        let error = Day11.solve_part1(&test_input).expect_err("There should be an error");
+
+        assert_eq!(error.to_string(), "Solver failure: The device bbb outputs to an unknown device: zzz");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,147 @@
+use crate::day_factory::register_day;
+use crate::utils::answer::Answer;
+use crate::utils::day::Day;
+use crate::utils::day_error::DayError;
+
+pub struct Day12;
+
+register_day!(Day12, year: 2025, title: "Christmas Tree Farm", tags: ["packing"]);
+
+#[allow(unused)]
+#[derive(Debug)]
+struct Point {
+    x: i64,
+    y: i64
+}
+
+#[allow(unused)]
+#[derive(Debug)]
+struct Present {
+    i: usize,
+    shape: Vec<Point>
+}
+
+impl Present {
+    /// Parses a present block, `line_offset` being the number of lines before it in the input.
+    fn create_from(line_offset: usize, value: &str) -> Result<Present, DayError> {
+        let header = value.lines().next().unwrap_or_default();
+        let (i_str, shape_str) = value.split_once(":\n")
+            .ok_or_else(|| DayError::parse(line_offset + 1, header, "Should be able to split on ':\\n'"))?;
+        let i = i_str.parse()
+            .map_err(|e| DayError::parse(line_offset + 1, header, "Should be able to parse idx").with_source(e))?;
+        let shape = shape_str.split('\n')
+            .enumerate()
+            .flat_map(|(i, r_str)| r_str.chars()
+                .enumerate()
+                .filter(|(_, c)| *c == '#')
+                .map(|(j, _)| Point { x: j as i64, y: i as i64 })
+                .collect::<Vec<Point>>()
+            )
+            .collect();
+        Ok(Present { i, shape })
+    }
+}
+
+#[derive(Debug)]
+struct Tree {
+    h: usize,
+    w: usize,
+    ids: Vec<usize>
+}
+
+impl Tree {
+    fn create_from(line_idx: usize, value: &str) -> Result<Tree, DayError> {
+        let line = line_idx + 1;
+        let (h_w_str, ids_str) = value.split_once(": ")
+            .ok_or_else(|| DayError::parse(line, value, "It should have ': '"))?;
+
+        let (w_str, h_str) = h_w_str.split_once('x')
+            .ok_or_else(|| DayError::parse(line, value, "It should have a 'x'"))?;
+        let h = h_str.parse()
+            .map_err(|e| DayError::parse(line, value, "h should be a usize").with_source(e))?;
+        let w = w_str.parse()
+            .map_err(|e| DayError::parse(line, value, "w should be a usize").with_source(e))?;
+
+        let ids = ids_str.split(' ')
+            .map(|i_str| i_str.parse())
+            .collect::<Result<Vec<usize>, _>>()
+            .map_err(|e| DayError::parse(line, value, "ids should be usize").at_column(h_w_str.len() + 3).with_source(e))?;
+
+        Ok(Tree { h, w, ids })
+    }
+}
+
+
+impl Tree {
+    fn is_valid(&self, presents: &[Present]) -> bool {
+        log::trace!("Curr tree:\n{:?}", self);
+        let filtered: Vec<(usize, &Present)> = self.ids.iter()
+            .enumerate()
+            .filter(|(_, id)| **id != 0)
+            .map(|(i, id)| (*id, &presents[i]))
+            .collect();
+
+        let min_s: usize = filtered.iter().map(|(i, p)| i * p.shape.len()).sum();
+        if self.h * self.w < min_s {
+            return false;
+        }
+
+        
+        
+        log::trace!("Filtered:\n{:?}", filtered);
+        false
+    }
+}
+
+pub struct Farm {
+    presents: Vec<Present>,
+    trees: Vec<Tree>
+}
+
+impl Day for Day12 {
+    type Input = Farm;
+
+    fn get_nb(&self) -> i8 {
+        12
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, DayError> {
+        log::trace!("Input:\n{input}");
+        let mut line_offset = 0;
+        let mut first_split: Vec<(usize, &str)> = vec![];
+        for block in input.split("\n\n") {
+            first_split.push((line_offset, block));
+            line_offset += block.split('\n').count() + 1;
+        }
+        let presents: Vec<Present> = first_split[0..first_split.len()-1]
+            .iter()
+            .map(|(block_offset, s)| Present::create_from(*block_offset, s))
+            .collect::<Result<Vec<Present>, DayError>>()?;
+
+        let (trees_offset, trees_str) = first_split[first_split.len()-1];
+        let trees: Vec<Tree> = trees_str.split('\n')
+            .enumerate()
+            .filter(|(_, s)| !s.trim().is_empty())
+            .map(|(line_idx, s)| Tree::create_from(line_idx + trees_offset, s))
+            .collect::<Result<Vec<Tree>, DayError>>()?;
+
+        Ok(Farm { presents, trees })
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer, DayError> {
+        let Farm { presents, trees } = input;
+        log::debug!("Presents:\n{:?}", presents);
+        log::debug!("Trees:\n{:?}", trees);
+
+        let result = trees.iter()
+            .take(1)
+            .filter(|t| t.is_valid(presents))
+            .count();
+
+        Ok(result.into())
+    }
+
+    fn part2(&self, _input: &Self::Input) -> Result<Answer, DayError> {
+        Err(DayError::Unsolved { part: 2 })
+    }
+}
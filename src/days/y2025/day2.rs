@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+
+use crate::day_factory::register_day;
+use crate::utils::answer::Answer;
+use crate::utils::day::Day;
+use crate::utils::day_error::DayError;
+use crate::utils::range::Range;
+
+pub struct Day2;
+
+register_day!(Day2, year: 2025, title: "Gift Shop", tags: ["brute-force", "strings"]);
+
+/// Parses the whole input, a single line of ranges separated by commas.
+fn create_all(input: &str) -> Result<Vec<Range>, DayError> {
+    let mut column = 1;
+    let mut ranges = vec![];
+    for range_str in input.split(',') {
+        let offset = range_str.len() - range_str.trim_start().len();
+        ranges.push(Range::parse(range_str.trim()).map_err(|e| e.shift_columns(column + offset - 1))?);
+        column += range_str.len() + 1;
+    }
+    Ok(ranges)
+}
+
+fn is_invalid_for_size(chars: &[char], size: usize) -> bool {
+    if size == 2 {
+        return chars[..chars.len()/2] == chars[chars.len()/2..];
+    }
+    let mut first_chunk_opt = None;
+    for chunk in chars.chunks_exact(size) {
+        if let Some(first_chunk) = first_chunk_opt {
+            if chunk != first_chunk {
+                return false;
+            }
+        } else {
+            first_chunk_opt = Some(chunk);
+        }
+    }
+    true
+}
+
+fn found_invalid_ids_part1(range: &Range) -> i64 {
+    let mut invalid_ids_sum: i64 = 0;
+
+    for i in range.start..=range.end {
+        let str_i = i.to_string();
+        if str_i.len() % 2 != 0 {
+            continue;
+        }
+
+        let chars = str_i.chars()
+            .collect::<Vec<char>>();
+        let is_invalid = is_invalid_for_size(&chars, 2);
+        if is_invalid {
+            invalid_ids_sum += i;
+        }
+
+        //let middle = str_i.len() / 2;
+        //let left = &str_i[..middle];
+        //let right = &str_i[middle..];
+        
+        //if left == right {
+        //    invalid_ids_sum += i;
+        //}
+    }
+
+    invalid_ids_sum
+}
+
+fn found_invalid_ids_part2(range: &Range, dividers_map: &mut HashMap<usize, Vec<usize>>) -> i64 {
+    let mut invalid_ids_sum: i64 = 0;
+
+    for i in range.start..=range.end {
+        let str_i = i.to_string();
+        let len_str = str_i.len();
+        let chars = str_i.chars()
+            .collect::<Vec<char>>();
+
+        let dividers = dividers_map.entry(len_str)
+            .or_insert_with(|| (1..len_str)
+                .filter(|try_div| len_str % try_div == 0)
+                .collect::<Vec<usize>>());
+
+        if dividers.iter().any(|size| is_invalid_for_size(&chars, *size)) {
+            invalid_ids_sum += i;
+        }
+    }
+
+    invalid_ids_sum
+}
+
+impl Day for Day2 {
+    type Input = Vec<Range>;
+
+    fn get_nb(&self) -> i8 {
+        2
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, DayError> {
+        create_all(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer, DayError> {
+        let result: i64 = input.iter()
+            .map(found_invalid_ids_part1)
+            .sum();
+
+        Ok(result.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer, DayError> {
+        let mut dividers_map= HashMap::new();
+
+        let result: i64 = input.iter()
+            .map(|range| found_invalid_ids_part2(range, &mut dividers_map))
+            .sum();
+
+        Ok(result.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::y2025::day2::Day2;
+    use crate::utils::day::Day;
+
+    #[test]
+    fn it_test_invalid_range() {
+        let test_input = "11-22, 95-1a5".to_string();
+        let error = Day2.solve_part1(&test_input).expect_err("There should be an error");
+
+        assert_eq!(error.to_string(), "Parse error at line 1, column 11: Failed to parse end (in `95-1a5`)");
+    }
+}
\ No newline at end of file
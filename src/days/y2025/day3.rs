@@ -0,0 +1,97 @@
+use crate::day_factory::register_day;
+use crate::utils::answer::Answer;
+use crate::utils::day::Day;
+use crate::utils::day_error::DayError;
+
+pub struct Day3;
+
+register_day!(Day3, year: 2025, title: "Lobby", tags: ["greedy"]);
+
+fn max(sub_str: &str) -> (usize, u64) {
+    let mut max_idx: usize = 0;
+    let mut max: u64 = 0;
+    for (idx, i) in sub_str.chars().map(|val| val.to_digit(10).unwrap() as u64).enumerate() {
+        if max < i {
+            max = i;
+            max_idx = idx;
+        }
+    }
+    (max_idx, max)
+}
+
+fn found_max_in_order(nb_to_peak: usize, line: &str) -> u64 {
+    let mut result = 0;
+    let mut prev_idx_opt = None;
+    for i in (0..nb_to_peak).rev() {
+        let max_val: u64;
+        if let Some(prev_idx) = prev_idx_opt {
+            let (idx_max, found_max) = max(&line[prev_idx+1..line.len()-i]);
+            prev_idx_opt = Some(idx_max + prev_idx + 1);
+            max_val = found_max;
+        } else {
+            let (idx_max, found_max) = max(&line[..line.len()-i]);
+            prev_idx_opt = Some(idx_max);
+            max_val = found_max;
+        }
+        result += max_val * 10_u64.pow(i as u32);
+    }
+    result
+}
+
+/// Checks that the bank only has digits.
+fn check_line(line_idx: usize, line: &str) -> Result<String, DayError> {
+    if let Some(column) = line.chars().position(|c| !c.is_ascii_digit()) {
+        return Err(DayError::parse(line_idx + 1, line, "A bank should only have digits").at_column(column + 1));
+    }
+    Ok(line.to_string())
+}
+
+fn total_joltage(banks: &[String], nb_to_peak: usize) -> Result<u64, DayError> {
+    if let Some(bank) = banks.iter().find(|bank| bank.len() < nb_to_peak) {
+        return Err(DayError::solver(format!("The bank {bank} should have at least {nb_to_peak} batteries")));
+    }
+
+    Ok(banks.iter()
+        .map(|bank| found_max_in_order(nb_to_peak, bank))
+        .sum())
+}
+
+impl Day for Day3 {
+    type Input = Vec<String>;
+
+    fn get_nb(&self) -> i8 {
+        3
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, DayError> {
+        input.split("\n")
+            .enumerate()
+            .filter(|(_, line)| line.trim() != "")
+            .map(|(line_idx, line)| check_line(line_idx, line))
+            .collect()
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer, DayError> {
+        let result = total_joltage(input, 2)?;
+        Ok(result.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer, DayError> {
+        let result = total_joltage(input, 12)?;
+        Ok(result.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::y2025::day3::Day3;
+    use crate::utils::day::Day;
+
+    #[test]
+    fn it_test_too_short_bank() {
+        let test_input = "987654321111111\n98765".to_string();
+        let error = Day3.solve_part2(&test_input).expect_err("There should be an error");
+
+        assert_eq!(error.to_string(), "Solver failure: The bank 98765 should have at least 12 batteries");
+    }
+}
\ No newline at end of file
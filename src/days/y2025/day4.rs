@@ -0,0 +1,79 @@
+use crate::day_factory::register_day;
+use crate::utils::answer::Answer;
+use crate::utils::day::Day;
+use crate::utils::day_error::DayError;
+use crate::utils::grid::{Grid, Position};
+
+pub struct Day4;
+
+register_day!(Day4, year: 2025, title: "Printing Department", tags: ["grid", "simulation"]);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Case {
+    Dot,
+    Roll
+}
+
+impl TryFrom<char> for Case {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Case::Dot),
+            '@' => Ok(Case::Roll),
+            e => Err(e)
+        }
+    }
+}
+
+fn is_roll_ok(grid: &Grid<Case>, position: Position) -> bool {
+    grid.neighbours8(position)
+        .filter(|neighbour| grid[*neighbour] == Case::Roll)
+        .take(4)
+        .count() < 4
+}
+
+fn get_accessible_rolls(grid: &Grid<Case>) -> Vec<Position> {
+    grid.find_all(&Case::Roll)
+        .filter(|position| is_roll_ok(grid, *position))
+        .collect()
+}
+
+fn update_grid(grid: &mut Grid<Case>, accessible_rolls: &[Position]) {
+    for roll in accessible_rolls {
+        grid[*roll] = Case::Dot;
+    }
+}
+
+impl Day for Day4 {
+    type Input = Grid<Case>;
+
+    fn get_nb(&self) -> i8 {
+        4
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, DayError> {
+        Grid::parse(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer, DayError> {
+        let accessible_rolls = get_accessible_rolls(input);
+        let result = accessible_rolls.len();
+
+        Ok(result.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer, DayError> {
+        let mut grid = input.clone();
+
+        let mut accessible_rolls = get_accessible_rolls(&grid);
+        let mut result = accessible_rolls.len();
+        while !accessible_rolls.is_empty() {
+            update_grid(&mut grid, &accessible_rolls);
+            accessible_rolls = get_accessible_rolls(&grid);
+            result += accessible_rolls.len();
+        }
+
+        Ok(result.into())
+    }
+}
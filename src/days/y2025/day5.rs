@@ -0,0 +1,77 @@
+use crate::day_factory::register_day;
+use crate::utils::answer::Answer;
+use crate::utils::day::Day;
+use crate::utils::day_error::DayError;
+use crate::utils::range::{merge_all, Range};
+
+pub struct Day5;
+
+register_day!(Day5, year: 2025, title: "Cafeteria", tags: ["ranges"]);
+
+pub struct Inventory {
+    ranges: Vec<Range>,
+    ids: Vec<i64>
+}
+
+/// Splits the input into the fresh ranges and the ids, the blank line being at index `ids_offset - 1`.
+fn split_input(input: &str) -> Result<(Vec<Range>, &str, usize), DayError> {
+    let (ranges_str, ids_str) = input.split_once("\n\n")
+        .ok_or_else(|| DayError::parse(1, input.lines().next().unwrap_or_default(), "The ranges and the ids should be separated by a blank line"))?;
+    let ranges = ranges_str.split('\n')
+        .enumerate()
+        .map(|(line_idx, range_str)| Range::parse(range_str).map_err(|e| e.shift_lines(line_idx)))
+        .collect::<Result<Vec<Range>, DayError>>()?;
+
+    let ids_offset = ranges.len() + 1;
+    Ok((ranges, ids_str, ids_offset))
+}
+
+impl Day for Day5 {
+    type Input = Inventory;
+
+    fn get_nb(&self) -> i8 {
+        5
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, DayError> {
+        let (ranges, ids_str, ids_offset) = split_input(input)?;
+
+        let ids = ids_str.split('\n')
+            .enumerate()
+            .filter(|(_, id_str)| id_str.trim() != "")
+            .map(|(line_idx, id_str)| id_str.parse::<i64>()
+                .map_err(|e| DayError::parse(line_idx + 1, id_str, "An id should be a number").shift_lines(ids_offset).with_source(e))
+            )
+            .collect::<Result<Vec<i64>, DayError>>()?;
+
+        Ok(Inventory { ranges, ids })
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer, DayError> {
+        let result = input.ids.iter()
+            .filter(|id| input.ranges.iter().any(|range| range.contains(**id)))
+            .count();
+        
+        Ok(result.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer, DayError> {
+        let result = merge_all(&input.ranges).iter().map(Range::size).sum::<i64>();
+        
+        Ok(result.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::y2025::day5::Day5;
+    use crate::utils::day::Day;
+
+    #[test]
+    fn it_test_invalid_id() {
+        let test_input = "3-5\n10-14\n\n1\nfive".to_string();
+        let error = Day5.solve_part1(&test_input).expect_err("There should be an error");
+
+        assert_eq!(error.to_string(), "Parse error at line 5: An id should be a number (in `five`)");
+    }
+}
\ No newline at end of file
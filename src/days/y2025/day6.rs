@@ -0,0 +1,161 @@
+use crate::day_factory::register_day;
+use crate::utils::answer::Answer;
+use crate::utils::day::Day;
+use crate::utils::day_error::DayError;
+
+pub struct Day6;
+
+register_day!(Day6, year: 2025, title: "Trash Compactor", tags: ["parsing", "grid"]);
+
+#[derive(Debug)]
+enum Op {
+    Plus,
+    Mult
+}
+
+impl TryFrom<&str> for Op {
+    type Error = ParseError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "*" => Ok(Op::Mult),
+            "+" => Ok(Op::Plus),
+            _ => Err(ParseError {})
+        }
+    }
+}
+
+struct ParseError {}
+
+impl TryFrom<char> for Op {
+    type Error = ParseError;
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '*' => Ok(Op::Mult),
+            '+' => Ok(Op::Plus),
+            _ => Err(ParseError {})
+        }
+    }
+}
+
+/// Checks that all the lines have the same width, each part measuring it its own way.
+fn check_widths(lines: &[(usize, String)], width_of: impl Fn(&str) -> usize) -> Result<usize, DayError> {
+    let w = width_of(&lines[0].1);
+    if let Some((line_idx, line)) = lines.iter().find(|(_, line)| width_of(line) != w) {
+        return Err(DayError::parse(line_idx + 1, line, format!("All the lines should have the same length: {w}")));
+    }
+    Ok(w)
+}
+
+fn split_line(line: &str) -> Vec<&str> {
+    line.split(' ').map(|g| g.trim()).filter(|e| !e.is_empty()).collect()
+}
+
+impl Op {
+    fn apply(&self, values: Vec<u128>) -> u128 {
+        match self {
+            Op::Mult => values.iter().product(),
+            Op::Plus => values.iter().sum()
+        }
+    }
+}
+
+impl Day for Day6 {
+    /// The lines with content, with their index in the input.
+    type Input = Vec<(usize, String)>;
+
+    fn get_nb(&self) -> i8 {
+        6
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, DayError> {
+        let lines: Vec<(usize, String)> = input.split('\n')
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(line_idx, line)| (line_idx, line.to_string()))
+            .collect();
+        let (_, first_line) = lines.first()
+            .ok_or_else(|| DayError::parse(1, "", "The input is empty"))?;
+        if lines.len() < 2 {
+            return Err(DayError::parse(1, first_line, "There should be at least one line of numbers and one of operators"));
+        }
+        Ok(lines)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer, DayError> {
+        let w = check_widths(input, |line| split_line(line).len())?;
+        let grid: Vec<(usize, &str, Vec<&str>)> = input.iter()
+            .map(|(line_idx, line)| (*line_idx, line.as_str(), split_line(line)))
+            .collect();
+        let h = grid.len();
+
+        let mut result = 0;
+        for j in 0..w {
+            let (op_idx, op_line, ops) = &grid[h-1];
+            let cur_op = Op::try_from(ops[j])
+                .map_err(|_| DayError::parse(op_idx + 1, op_line, format!("Unknown operator: {}", ops[j])))?;
+            let values: Vec<u128> = grid[..h-1].iter()
+                .map(|(line_idx, line, row)| row[j].parse()
+                    .map_err(|e| DayError::parse(line_idx + 1, line, format!("{} should be a number", row[j])).with_source(e))
+                )
+                .collect::<Result<Vec<u128>, DayError>>()?;
+
+            result += cur_op.apply(values);
+        }
+
+        Ok(result.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer, DayError> {
+        let w = check_widths(input, |line| line.chars().count())?;
+        let grid: Vec<Vec<char>> = input.iter()
+            .map(|(_, line)| line.chars().collect::<Vec<char>>())
+            .collect();
+        let h = grid.len();
+
+        let mut result = 0;
+        let mut last_op = Op::Mult;
+        let mut values_acc: Vec<u128> = vec![];
+        for j in 0..w {
+            let cur_op_res = Op::try_from(grid[h-1][j]);
+            if let Ok(cur_op) = cur_op_res {
+                last_op = cur_op;
+            }
+            
+
+            let value_opt: Option<u128> = grid[..h-1].iter()
+                .map(|row| row[j])
+                .map(|val| val.to_digit(10))
+                .fold(
+                    None, 
+                    |acc_opt, val_opt| 
+                        if let Some(val) = val_opt {
+                            if let Some(acc) = acc_opt { Some(acc * 10 + val as u128)} else { Some(val as u128) }
+                        } else { acc_opt }
+                );
+            if let Some(value) = value_opt {
+                values_acc.push(value);
+            } else {
+                result += last_op.apply(values_acc);
+                values_acc = vec![];
+            }
+        }
+
+        result += last_op.apply(values_acc);
+
+        Ok(result.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::y2025::day6::Day6;
+    use crate::utils::day::Day;
+
+    #[test]
+    fn it_test_unknown_operator() {
+        let test_input = "123 328\n 45 64 \n*   -  ".to_string();
+        let error = Day6.solve_part1(&test_input).expect_err("There should be an error");
+
+        assert_eq!(error.to_string(), "Parse error at line 3: Unknown operator: - (in `*   -  `)");
+    }
+}
\ No newline at end of file
@@ -1,26 +1,32 @@
 use std::collections::{HashMap, HashSet};
-#[allow(unused_imports)]
-use std::{fmt, thread, time};
+use std::fmt;
 
+use crate::day_factory::register_day;
+use crate::utils::answer::Answer;
 use crate::utils::day::Day;
 use crate::utils::day_error::DayError;
+use crate::utils::grid::Grid;
 
 pub struct Day7;
 
-#[derive(PartialEq)]
+register_day!(Day7, year: 2025, title: "Laboratories", tags: ["grid", "dynamic-programming"]);
+
+#[derive(PartialEq, Clone)]
 enum Cell {
     Start,
     Dot,
     Splitter
 }
 
-impl From<char> for Cell {
-    fn from(value: char) -> Self {
+impl TryFrom<char> for Cell {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
-            'S' => Cell::Start,
-            '.' => Cell::Dot,
-            '^' => Cell::Splitter,
-            e => panic!("This character is not ok {e}")
+            'S' => Ok(Cell::Start),
+            '.' => Ok(Cell::Dot),
+            '^' => Ok(Cell::Splitter),
+            e => Err(e)
         }
     }
 }
@@ -35,41 +41,29 @@ impl fmt::Display for Cell {
     }
 }
 
-struct Puzzle {
-    m: Vec<Vec<Cell>>,
-    h: usize,
-    w: usize,
+#[derive(Clone)]
+pub struct Puzzle {
+    m: Grid<Cell>,
     all_beams: HashMap<(usize, usize), usize>,
     cur_beams: HashSet<(usize, usize)>,
     iter: usize,
     res: i64
 }
 
-impl From<String> for Puzzle {
-    fn from(value: String) -> Self {
-        let matrix: Vec<Vec<Cell>> = value.split('\n')
-            .filter(|row| !row.trim().is_empty())
-            .map(|row| row.chars().map(Cell::from).collect::<Vec<Cell>>())
-            .collect();
-        let h = matrix.len();
-        let w = matrix[0].len();
-
-        let start = matrix.iter()
-            .enumerate()
-            .map(|(i, row)| (
-                i,
-                row.iter().enumerate().find(|(_, c)| Cell::Start == **c)
-            ))
-            .find(|(_, found_row)| found_row.is_some())
-            .map(|(i, c)| (i, c.unwrap().0))
-            .expect("There must be a start");
+impl TryFrom<&str> for Puzzle {
+    type Error = DayError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let matrix: Grid<Cell> = Grid::parse(value)?;
+        let start = matrix.find(&Cell::Start)
+            .ok_or_else(|| DayError::parse(1, value.lines().next().unwrap_or_default(), "There must be a start"))?;
 
         let mut all_beams: HashMap<(usize, usize), usize> = HashMap::new();
         all_beams.insert(start, 1);
         let mut cur_beams: HashSet<(usize, usize)> = HashSet::new();
         cur_beams.insert(start);
 
-        Puzzle { m: matrix, all_beams, cur_beams, res: 0, h, w, iter: 0 }
+        Ok(Puzzle { m: matrix, all_beams, cur_beams, res: 0, iter: 0 })
     }
 }
 
@@ -77,9 +71,9 @@ impl fmt::Display for Puzzle {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "It: {}, res: {}", self.iter, self.res)?;
 
-        for i in 0..self.h {
-            for j in 0..self.w {
-                let c = &self.m[i][j];
+        for i in 0..self.m.height() {
+            for j in 0..self.m.width() {
+                let c = &self.m[(i, j)];
                 if let Cell::Start = c {
                     write!(f, " {c}")?;
                 } else if let Some(nb_beam) = self.all_beams.get(&(i, j)) {
@@ -88,7 +82,7 @@ impl fmt::Display for Puzzle {
                     write!(f, " {c}")?;
                 }
             }
-            writeln!(f, "")?;
+            writeln!(f)?;
         }
         
         let last_beams: Vec<(&(usize, usize), &usize)> = self.all_beams.iter()
@@ -104,9 +98,9 @@ impl Puzzle {
     fn step(&mut self) {
         self.iter += 1;
         let mut new_beams: HashSet<(usize, usize)> = HashSet::new();
-        for beam in self.cur_beams.iter().filter(|(i, _)| *i != self.h - 1) {
+        for beam in self.cur_beams.iter().filter(|(i, _)| *i != self.m.height() - 1) {
             let nb_beams = self.all_beams[beam];
-            if let Cell::Splitter = &self.m[beam.0 + 1][beam.1] {
+            if let Cell::Splitter = &self.m[(beam.0 + 1, beam.1)] {
                 self.res += 1;
                 if 1 <= beam.1 {
                     let new_beam = (beam.0 + 1, beam.1 - 1);
@@ -116,7 +110,7 @@ impl Puzzle {
                         .or_insert(nb_beams);
                 }
 
-                if beam.1 + 1 < self.w {
+                if beam.1 + 1 < self.m.width() {
                     let new_beam = (beam.0 + 1, beam.1 + 1);
                     new_beams.insert(new_beam);
                     self.all_beams.entry(new_beam)
@@ -137,12 +131,18 @@ impl Puzzle {
 }
 
 impl Day for Day7 {
+    type Input = Puzzle;
+
     fn get_nb(&self) -> i8 {
         7
     }
 
-    fn part1(&self, input: String) -> Result<i64, DayError<'_>> {
-        let mut p = Puzzle::from(input);
+    fn parse(&self, input: &str) -> Result<Self::Input, DayError> {
+        Puzzle::try_from(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer, DayError> {
+        let mut p = input.clone();
         // println!("Puzzle start:\n{p}");
 
         while !p.cur_beams.is_empty() {
@@ -153,11 +153,11 @@ impl Day for Day7 {
             // println!("{p}");
         }
         
-        Ok(p.res)
+        Ok(p.res.into())
     }
 
-    fn part2(&self, input: String) -> Result<i64, DayError<'_>> {
-        let mut p = Puzzle::from(input);
+    fn part2(&self, input: &Self::Input) -> Result<Answer, DayError> {
+        let mut p = input.clone();
 
         while !p.cur_beams.is_empty() {
             p.step();
@@ -168,86 +168,22 @@ impl Day for Day7 {
             .map(|(_, nb_beam)| nb_beam)
             .sum();
         
-        Ok(result as i64)
+        Ok(result.into())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::days::day7::Day7;
+    use crate::days::y2025::day7::Day7;
     use crate::utils::day::Day;
 
     #[test]
-    fn it_test_example_part1() {
+    fn it_test_missing_start() {
         let test_input = 
-".......S.......
-...............
-.......^.......
-...............
-......^.^......
-...............
-.....^.^.^.....
-...............
-....^.^...^....
-...............
-...^.^...^.^...
-...............
-..^...^.....^..
-...............
-.^.^.^.^.^...^.
-...............".to_string();
-        let result = Day7.part1(test_input).expect("There should be a result");
-
-        assert_eq!(result, 21);
-    }
+"...............
+.......^.......".to_string();
+        let error = Day7.solve_part1(&test_input).expect_err("There should be an error");
 
-    #[test]
-    fn it_test_example_part2_simple() {
-        let test_input = 
-".......S.......
-...............
-.......^.......
-...............".to_string();
-        let result = Day7.part2(test_input).expect("There should be a result");
-
-        assert_eq!(result, 2);
-    }
-
-    #[test]
-    fn it_test_example_part2_medium() {
-        let test_input = 
-".......S.......
-...............
-.......^.......
-...............
-......^.^......
-...............".to_string();
-        let result = Day7.part2(test_input).expect("There should be a result");
-
-        assert_eq!(result, 4);
-    }
-
-    #[test]
-    fn it_test_example_part2() {
-        let test_input = 
-".......S.......
-...............
-.......^.......
-...............
-......^.^......
-...............
-.....^.^.^.....
-...............
-....^.^...^....
-...............
-...^.^...^.^...
-...............
-..^...^.....^..
-...............
-.^.^.^.^.^...^.
-...............".to_string();
-        let result = Day7.part2(test_input).expect("There should be a result");
-
-        assert_eq!(result, 40);
+        assert_eq!(error.to_string(), "Parse error at line 1: There must be a start (in `...............`)");
     }
 }
\ No newline at end of file
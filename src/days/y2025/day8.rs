@@ -1,11 +1,15 @@
 use std::cell::RefCell;
 use std::collections::HashSet;
 
+use crate::day_factory::register_day;
+use crate::utils::answer::Answer;
 use crate::utils::day::Day;
 use crate::utils::day_error::DayError;
 
 pub struct Day8;
 
+register_day!(Day8, year: 2025, title: "Playground", tags: ["graph", "geometry"]);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct Junction {
     x: i64,
@@ -13,34 +17,35 @@ struct Junction {
     z: i64
 }
 
-#[derive(Debug)]
-struct Day8Error {}
-
-impl TryFrom<&str> for Junction {
-    type Error = Day8Error;
-
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
+impl Junction {
+    fn create_from(line_idx: usize, value: &str) -> Result<Junction, DayError> {
         let s: Vec<i64> = value.split(',')
-            .filter(|c| !c.trim().is_empty())
-            .map(|c| c.parse::<i64>())
-            .filter(Result::is_ok)
-            .map(Result::unwrap)
-            .collect();
+            .map(|c| c.trim().parse::<i64>())
+            .collect::<Result<Vec<i64>, _>>()
+            .map_err(|e| DayError::parse(line_idx + 1, value, "A coordinate should be a number").with_source(e))?;
         if s.len() != 3 {
-            return Err(Day8Error {  });
+            return Err(DayError::parse(line_idx + 1, value, "A junction should have 3 coordinates"));
         }
 
         Ok(Junction { x: s[0], y: s[1], z: s[2] })
     }
 }
 
+fn create_junctions(input: &str) -> Result<Vec<Junction>, DayError> {
+    input.split('\n')
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(line_idx, line)| Junction::create_from(line_idx, line))
+        .collect()
+}
+
 impl Junction {
     fn dist(&self, o: &Junction) -> f64 {
-        ((((self.x - o.x).pow(2) + (self.y - o.y).pow(2) + (self.z - o.z).pow(2))) as f64).sqrt()
+        (((self.x - o.x).pow(2) + (self.y - o.y).pow(2) + (self.z - o.z).pow(2)) as f64).sqrt()
     }
 }
 
-fn get_all_pairs(j_list1: &Vec<Junction>, j_list2: &Vec<Junction>) -> Vec<(usize, usize, f64)> {
+fn get_all_pairs(j_list1: &[Junction], j_list2: &[Junction]) -> Vec<(usize, usize, f64)> {
     j_list1.iter()
         .enumerate()
         .flat_map(|(i_1, j_1)| j_list2[i_1+1..].iter()
@@ -52,22 +57,35 @@ fn get_all_pairs(j_list1: &Vec<Junction>, j_list2: &Vec<Junction>) -> Vec<(usize
         .collect()
 }
 
+pub struct Playground {
+    junctions: Vec<Junction>,
+    /// Every pair of junctions with their distance, from the closest to the farthest.
+    all_pairs: Vec<(usize, usize, f64)>
+}
+
 impl Day for Day8 {
+    type Input = Playground;
+
     fn get_nb(&self) -> i8 {
         8
     }
 
-    fn part1(&self, input: String) -> Result<i64, DayError<'_>> {
-        let nb_iter = 1000;
-
-        let junctions: Vec<Junction> = input.split('\n')
-            .map(Junction::try_from)
-            .filter(Result::is_ok)
-            .map(Result::unwrap)
-            .collect();
+    fn parse(&self, input: &str) -> Result<Self::Input, DayError> {
+        let junctions = create_junctions(input)?;
 
         let mut all_pairs = get_all_pairs(&junctions, &junctions);
         all_pairs.sort_by(|(_, _, d1), (_, _, d2)| d1.total_cmp(d2));
+
+        Ok(Playground { junctions, all_pairs })
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer, DayError> {
+        let nb_iter = 1000;
+
+        let Playground { junctions, all_pairs } = input;
+        if all_pairs.len() < nb_iter {
+            return Err(DayError::solver(format!("There are only {} pairs, {nb_iter} are needed", all_pairs.len())));
+        }
         let closest_pairs: Vec<(&Junction, &Junction, f64)> = all_pairs[..nb_iter].iter()
             .map(|(i1, i2, d)| (&junctions[*i1], &junctions[*i2], *d))
             .collect();
@@ -94,7 +112,7 @@ impl Day for Day8 {
                 groups.remove(matching_group[1].0);
 
             } else {
-                panic!("Len != 2, should not happen");
+                return Err(DayError::solver(format!("A pair matched {} groups, it should not happen", matching_group.len())));
             }
 
         }
@@ -102,27 +120,22 @@ impl Day for Day8 {
             .map(|r| r.borrow().len()).collect();
         result_list.sort();
         let length = result_list.len();
-        let result = result_list[length-3..length].iter()
-            .fold(1, |acc, l| acc * l);
+        if length < 3 {
+            return Err(DayError::solver(format!("There are only {length} groups, 3 are needed")));
+        }
+        let result: usize = result_list[length-3..length].iter().product();
 
-        Ok(result as i64)
+        Ok(result.into())
     }
 
-    fn part2(&self, input: String) -> Result<i64, DayError<'_>> {
-        let junctions: Vec<Junction> = input.split('\n')
-            .map(Junction::try_from)
-            .filter(Result::is_ok)
-            .map(Result::unwrap)
-            .collect();
-
-        let mut all_pairs = get_all_pairs(&junctions, &junctions);
-        all_pairs.sort_by(|(_, _, d1), (_, _, d2)| d1.total_cmp(d2));
+    fn part2(&self, input: &Self::Input) -> Result<Answer, DayError> {
+        let Playground { junctions, all_pairs } = input;
 
         let mut groups: Vec<RefCell<HashSet<&Junction>>> = vec![];
         let mut i = 0; // To avoid infinite loop
         let max_length = junctions.len();
         let mut j_set: HashSet<&Junction> = HashSet::new();
-        while j_set.len() != max_length && i < 100_000 {
+        while j_set.len() != max_length && i < 100_000 && i < all_pairs.len() {
             let pair = all_pairs[i];
             let j_1 = &junctions[pair.0];
             let j_2 = &junctions[pair.1];
@@ -148,79 +161,20 @@ impl Day for Day8 {
                 g_1.borrow_mut().extend(g_2.borrow().iter());
                 groups.remove(matching_group[1].0);
             } else {
-                panic!("Len != 2, should not happen");
+                return Err(DayError::solver(format!("A pair matched {} groups, it should not happen", matching_group.len())));
             }
 
             i += 1;
         }
 
+        if i == 0 || j_set.len() != max_length {
+            return Err(DayError::solver(format!("Only {} junctions out of {max_length} were connected after {i} pairs", j_set.len())));
+        }
         let last_pair = all_pairs[i-1];
         let j_1 = &junctions[last_pair.0];
         let j_2 = &junctions[last_pair.1];
         let result = j_1.x * j_2.x;
 
-        Ok(result as i64)
+        Ok(result.into())
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use crate::days::day8::Day8;
-    use crate::utils::day::Day;
-
-    #[test]
-    fn it_test_example_part1() {
-        let test_input = 
-"162,817,812
-57,618,57
-906,360,560
-592,479,940
-352,342,300
-466,668,158
-542,29,236
-431,825,988
-739,650,466
-52,470,668
-216,146,977
-819,987,18
-117,168,530
-805,96,715
-346,949,466
-970,615,88
-941,993,340
-862,61,35
-984,92,344
-425,690,689".to_string();
-        let result = Day8.part1(test_input).expect("There should be a result");
-
-        assert_eq!(result, 40);
-    }
-
-    #[test]
-    fn it_test_example_part2() {
-        let test_input = 
-"162,817,812
-57,618,57
-906,360,560
-592,479,940
-352,342,300
-466,668,158
-542,29,236
-431,825,988
-739,650,466
-52,470,668
-216,146,977
-819,987,18
-117,168,530
-805,96,715
-346,949,466
-970,615,88
-941,993,340
-862,61,35
-984,92,344
-425,690,689".to_string();
-        let result = Day8.part2(test_input).expect("There should be a result");
-
-        assert_eq!(result, 25272);
-    }
-}
\ No newline at end of file
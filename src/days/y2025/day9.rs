@@ -1,25 +1,32 @@
 use itertools::Itertools;
 use std::collections::{HashMap, HashSet};
 
+use crate::day_factory::register_day;
+use crate::utils::answer::Answer;
 use crate::utils::day::Day;
 use crate::utils::day_error::DayError;
 
 pub struct Day9;
 
+register_day!(Day9, year: 2025, title: "Movie Theater", tags: ["geometry"]);
+
 #[derive(Debug, PartialEq, PartialOrd, Eq, Hash, Clone)]
-struct Tile {
+pub struct Tile {
     x: i64,
     y: i64
 }
 
-impl From<&str> for Tile {
-    fn from(value: &str) -> Self {
-        let (x_str, y_str) = value.split_once(',').expect("' not found");
-        Tile { x: x_str.parse().expect("x unwrap"), y: y_str.parse().expect("y unwrap") }
+impl Tile {
+    fn create_from(line_idx: usize, value: &str) -> Result<Tile, DayError> {
+        let (x_str, y_str) = value.split_once(',')
+            .ok_or_else(|| DayError::parse(line_idx + 1, value, "',' not found"))?;
+        let x = x_str.parse()
+            .map_err(|e| DayError::parse(line_idx + 1, value, "x should be a number").at_column(1).with_source(e))?;
+        let y = y_str.parse()
+            .map_err(|e| DayError::parse(line_idx + 1, value, "y should be a number").at_column(x_str.len() + 2).with_source(e))?;
+        Ok(Tile { x, y })
     }
-}
 
-impl Tile {
     fn area(&self, o: &Tile) -> i64 {
         ((self.x - o.x).abs() + 1) * ((self.y - o.y).abs() + 1)
     }
@@ -113,31 +120,35 @@ impl Square {
     }
 }
 
+fn create_tiles(input: &str) -> Result<Vec<Tile>, DayError> {
+    input.split('\n')
+        .enumerate()
+        .filter(|(_, s)| !s.trim().is_empty())
+        .map(|(line_idx, s)| Tile::create_from(line_idx, s))
+        .collect()
+}
+
 impl Day for Day9 {
+    type Input = Vec<Tile>;
+
     fn get_nb(&self) -> i8 {
         9
     }
 
-    fn part1(&self, input: String) -> Result<i64, DayError<'_>> {
-        let tiles: Vec<Tile> = input.split('\n')
-            .filter(|s| !s.trim().is_empty())
-            .map(Tile::from)
-            .collect();
+    fn parse(&self, input: &str) -> Result<Self::Input, DayError> {
+        create_tiles(input)
+    }
 
+    fn part1(&self, tiles: &Self::Input) -> Result<Answer, DayError> {
         let result = tiles.iter()
             .flat_map(|t_1| tiles.iter().map(|t_2| t_1.area(t_2)))
             .max()
-            .expect("Max not found");
+            .ok_or_else(|| DayError::solver("Max not found, there is no tile"))?;
 
-        Ok(result)
+        Ok(result.into())
     }
 
-    fn part2(&self, input: String) -> Result<i64, DayError<'_>> {
-        let tiles: Vec<Tile> = input.split('\n')
-            .filter(|s| !s.trim().is_empty())
-            .map(Tile::from)
-            .collect();
-
+    fn part2(&self, tiles: &Self::Input) -> Result<Answer, DayError> {
         let perimeter: HashSet<Tile> = tiles.iter()
             .circular_tuple_windows()
             .flat_map(|(t_1, t_2)| t_1.get_edge(t_2))
@@ -165,92 +176,21 @@ impl Day for Day9 {
                     y_acc.insert(t.y, (t.x, t.x));
                 }
 
-                return (x_acc, y_acc);
+                (x_acc, y_acc)
             });
         
         let res_square = tiles.iter()
             .enumerate()
-            .map(|(i_1, t_1)|
+            .filter_map(|(i_1, t_1)|
                 tiles[i_1+1..].iter()
                     .filter(|t_2| !t_1.is_aligned(t_2))
-                    .map(|t_2| Square::create_square(&x_map, &y_map, t_1, t_2))
-                    .filter(Option::is_some)
-                    .map(Option::unwrap)
+                    .filter_map(|t_2| Square::create_square(&x_map, &y_map, t_1, t_2))
                     .filter(|s| s.is_valid(&x_map, &y_map))
                     .max_by_key(Square::size)
-            ).filter(Option::is_some)
-            .map(Option::unwrap)
+            )
             .max_by_key(Square::size)
-            .expect("Should have at least one max");
+            .ok_or_else(|| DayError::solver("Should have at least one max"))?;
 
-        Ok(res_square.size())
+        Ok(res_square.size().into())
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use crate::days::day9::Day9;
-    use crate::utils::day::Day;
-
-    #[test]
-    fn it_test_example_part1() {
-        let test_input = 
-"7,1
-11,1
-11,7
-9,7
-9,5
-2,5
-2,3
-7,3".to_string();
-        let result = Day9.part1(test_input).expect("There should be a result");
-
-        assert_eq!(result, 50);
-    }
-
-    #[test]
-    fn it_test_example_part2() {
-        let test_input = 
-"7,1
-11,1
-11,7
-9,7
-9,5
-2,5
-2,3
-7,3".to_string();
-        let result = Day9.part2(test_input).expect("There should be a result");
-
-        assert_eq!(result, 24);
-    }
-
-    #[test]
-    fn it_test_example_simple_part2() {
-        let test_input = 
-"2,1
-12,1
-12,3
-8,3
-8,5
-2,5".to_string();
-        let result = Day9.part2(test_input).expect("There should be a result");
-
-        assert_eq!(result, 35);
-    }
-
-    #[test]
-    fn it_test_example_medium_part2() {
-        let test_input = 
-"2,1
-12,1
-12,3
-10,3
-10,5
-12,5
-12,7
-2,7".to_string();
-        let result = Day9.part2(test_input).expect("There should be a result");
-
-        assert_eq!(result, 45);
-    }
-}
\ No newline at end of file
@@ -0,0 +1,35 @@
+//! Solutions of the Advent of Code puzzles, with the tools to fetch the inputs, run, check and submit them.
+//!
+//! Every day implements [`Day`] and registers itself, so it can be used directly or found in the registry:
+//!
+//! ```
+//! use aoc_rust::days::y2025::day1::Day1;
+//! use aoc_rust::{find_day, Day};
+//!
+//! let input = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
+//! assert_eq!(Day1.solve_part1(input).expect("There should be a result"), 3);
+//!
+//! let entry = find_day(2025, 1).expect("The day 1 should be registered");
+//! let parsed = entry.solver.parse(input).expect("There should be a parsed input");
+//! assert_eq!(entry.solver.part2(&parsed).expect("There should be a result").to_string(), "6");
+//! ```
+
+pub mod answers;
+pub mod bench;
+pub mod cli;
+pub mod dashboard;
+pub mod days;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+#[cfg(feature = "tui")]
+pub mod tui;
+pub mod utils;
+pub mod watch;
+pub mod day_factory;
+
+pub use day_factory::{all_days, days_of_year, find_day, DayEntry};
+pub use utils::answer::Answer;
+pub use utils::day::{Day, ParsedInput, Solver};
+pub use utils::day_error::DayError;
+pub use utils::range::Range;
@@ -0,0 +1,186 @@
+use std::fmt;
+use std::time::Duration;
+
+use serde::{Serialize, Serializer};
+
+/// How the results of a run are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum OutputFormat {
+    /// The human readable lines printed while running.
+    #[default]
+    Text,
+    /// One JSON object per part.
+    Json,
+    Markdown,
+    Junit
+}
+
+impl OutputFormat {
+    pub fn from_word(word: &str) -> Option<OutputFormat> {
+        match word {
+            "text" => Some(OutputFormat::Text),
+            "json" => Some(OutputFormat::Json),
+            "markdown" | "md" => Some(OutputFormat::Markdown),
+            "junit" => Some(OutputFormat::Junit),
+            _ => None
+        }
+    }
+}
+
+fn as_nanos<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(duration.as_nanos())
+}
+
+/// The result of a part, as collected by the runner.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PartResult {
+    pub year: i16,
+    pub day: i8,
+    pub part: i8,
+    pub answer: Option<String>,
+    #[serde(rename = "duration_ns", serialize_with = "as_nanos")]
+    pub duration: Duration,
+    pub error: Option<String>
+}
+
+impl PartResult {
+    pub fn new(year: i16, day: i8, part: i8, outcome: Result<String, String>, duration: Duration) -> PartResult {
+        let (answer, error) = match outcome {
+            Ok(answer) => (Some(answer), None),
+            Err(error) => (None, Some(error))
+        };
+        PartResult { year, day, part, answer, duration, error }
+    }
+}
+
+/// One JSON object per line and per part.
+pub fn to_json_lines(results: &[PartResult]) -> Result<String, String> {
+    results.iter()
+        .map(|result| serde_json::to_string(result)
+            .map(|line| line + "\n")
+            .map_err(|e| format!("Unable to serialize the results: {e}")))
+        .collect()
+}
+
+/// Keeps a cell of a Markdown table on one line.
+fn markdown_cell(value: &str) -> String {
+    value.replace('|', "\\|").replace('\n', "<br>")
+}
+
+pub fn to_markdown(results: &[PartResult]) -> String {
+    let mut table = "| Year | Day | Part | Answer | Time | Error |\n|---:|---:|---:|---|---:|---|\n".to_string();
+    for result in results {
+        table.push_str(&format!(
+            "| {} | {} | {} | {} | {:.2?} | {} |\n",
+            result.year,
+            result.day,
+            result.part,
+            result.answer.as_deref().map(markdown_cell).unwrap_or_default(),
+            result.duration,
+            result.error.as_deref().map(markdown_cell).unwrap_or_default()
+        ));
+    }
+    table
+}
+
+/// Escapes the text and the attribute values of the XML.
+struct Xml<'a>(&'a str);
+
+impl fmt::Display for Xml<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for c in self.0.chars() {
+            match c {
+                '&' => write!(f, "&amp;")?,
+                '<' => write!(f, "&lt;")?,
+                '>' => write!(f, "&gt;")?,
+                '"' => write!(f, "&quot;")?,
+                '\'' => write!(f, "&apos;")?,
+                c => write!(f, "{c}")?
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A JUnit report with a test suite per year and a test case per part. The failed parts are the failures.
+pub fn to_junit(results: &[PartResult]) -> String {
+    let mut years: Vec<i16> = results.iter().map(|result| result.year).collect();
+    years.sort();
+    years.dedup();
+
+    let mut xml = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites>\n".to_string();
+    for year in years {
+        let suite: Vec<&PartResult> = results.iter().filter(|result| result.year == year).collect();
+        let failures = suite.iter().filter(|result| result.error.is_some()).count();
+        let time: Duration = suite.iter().map(|result| result.duration).sum();
+        xml.push_str(&format!(
+            "  <testsuite name=\"aoc {year}\" tests=\"{}\" failures=\"{failures}\" time=\"{:.6}\">\n",
+            suite.len(),
+            time.as_secs_f64()
+        ));
+        for result in suite {
+            xml.push_str(&format!(
+                "    <testcase classname=\"y{year}.day{}\" name=\"part{}\" time=\"{:.6}\">",
+                result.day,
+                result.part,
+                result.duration.as_secs_f64()
+            ));
+            if let Some(error) = &result.error {
+                xml.push_str(&format!("<failure message=\"{}\"/>", Xml(error)));
+            }
+            if let Some(answer) = &result.answer {
+                xml.push_str(&format!("<system-out>{}</system-out>", Xml(answer)));
+            }
+            xml.push_str("</testcase>\n");
+        }
+        xml.push_str("  </testsuite>\n");
+    }
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::report::{to_json_lines, to_junit, to_markdown, PartResult};
+
+    fn results() -> Vec<PartResult> {
+        vec![
+            PartResult::new(2025, 4, 1, Ok("1564".to_string()), Duration::from_micros(1500)),
+            PartResult::new(2025, 12, 1, Ok("#|#\n.#.".to_string()), Duration::from_nanos(20)),
+            PartResult::new(2025, 12, 2, Err("The part 2 is not solved yet & <never>".to_string()), Duration::ZERO)
+        ]
+    }
+
+    #[test]
+    fn it_test_json_lines() {
+        let json = to_json_lines(&results()).expect("There should be a result");
+        let lines: Vec<&str> = json.lines().collect();
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], r#"{"year":2025,"day":4,"part":1,"answer":"1564","duration_ns":1500000,"error":null}"#);
+        assert_eq!(lines[2], r#"{"year":2025,"day":12,"part":2,"answer":null,"duration_ns":0,"error":"The part 2 is not solved yet & <never>"}"#);
+    }
+
+    #[test]
+    fn it_test_markdown() {
+        let table = to_markdown(&results());
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert_eq!(lines[0], "| Year | Day | Part | Answer | Time | Error |");
+        assert_eq!(lines[2], "| 2025 | 4 | 1 | 1564 | 1.50ms |  |");
+        assert_eq!(lines[3], "| 2025 | 12 | 1 | #\\|#<br>.#. | 20.00ns |  |");
+        assert_eq!(lines.len(), 5);
+    }
+
+    #[test]
+    fn it_test_junit() {
+        let xml = to_junit(&results());
+
+        assert!(xml.contains(r#"<testsuite name="aoc 2025" tests="3" failures="1" time="0.001500">"#));
+        assert!(xml.contains(r#"<testcase classname="y2025.day4" name="part1" time="0.001500"><system-out>1564</system-out></testcase>"#));
+        assert!(xml.contains(r#"<failure message="The part 2 is not solved yet &amp; &lt;never&gt;"/>"#));
+        assert!(xml.ends_with("</testsuites>\n"));
+    }
+}
@@ -0,0 +1,842 @@
+//! The commands of the binary, so other tools can run them too.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{self, ExitCode, Stdio};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use rayon::prelude::*;
+
+use crate::{answers, bench, cli, day_factory, report, scaffold, utils, watch};
+
+/// Runs the command of the parsed arguments, printing its results.
+pub fn run(args: &cli::Args) -> ExitCode {
+    if args.command.runs_solvers() && day_factory::days_of_year(args.year).is_empty() {
+        eprintln!("There is no registered day for the year {}.", args.year);
+        return ExitCode::from(2);
+    }
+
+    if args.list {
+        list_days(args.year);
+        return ExitCode::SUCCESS;
+    }
+
+    if !args.quiet && args.command != cli::Command::Tui {
+        println!("Let's start Advent of Code {}!!!", args.year);
+    }
+
+    let success = match (&args.command, &args.days) {
+        (cli::Command::Bench, _) => bench_days(args),
+        (cli::Command::Verify, _) => verify_days(args),
+        (cli::Command::Submit, _) => report(submit_answer(args)),
+        (cli::Command::Login, _) => report(login(args)),
+        (cli::Command::Whoami, _) => report(whoami(args)),
+        (cli::Command::Examples, _) => report(extract_examples(args)),
+        (cli::Command::Cache(action), _) => report(manage_cache(*action, args)),
+        (cli::Command::New, _) => report(new_day(args)),
+        (cli::Command::Watch, _) => report(watch_day(args)),
+        (cli::Command::Tui, _) => report(show_dashboard(args)),
+        (cli::Command::Run, None) => run_all_days(args),
+        (cli::Command::Run, Some(_)) => run_selected_days(args)
+    };
+
+    if success { ExitCode::SUCCESS } else { ExitCode::FAILURE }
+}
+
+/// Prints the error if any, and tells if everything went well.
+fn report(result: Result<(), String>) -> bool {
+    if let Err(message) = &result {
+        eprintln!("{message}");
+    }
+    result.is_ok()
+}
+
+/// Formats the error followed by all its sources.
+pub fn error_chain(error: &dyn Error) -> String {
+    let mut message = error.to_string();
+    let mut source = error.source();
+    while let Some(cause) = source {
+        message.push_str(&format!(": {cause}"));
+        source = cause.source();
+    }
+    message
+}
+
+fn print_answer(day_nb: i8, part: i8, answer: &utils::answer::Answer, elapsed: Option<Duration>) {
+    let timing = elapsed.map(|elapsed| format!(" (in {elapsed:.2?})")).unwrap_or_default();
+    if answer.is_multiline() {
+        println!("Day {day_nb} part {part} result found{timing}:\n{answer}");
+    } else {
+        println!("Day {day_nb} part {part} result found: {answer}{timing}");
+    }
+}
+
+pub fn list_days(year: i16) {
+    for entry in day_factory::days_of_year(year) {
+        println!("{} day {:>2}  {:<25} [{}]", entry.year, entry.day(), entry.title, entry.tags.join(", "));
+    }
+}
+
+/// The input of the day: the `--input` file, the example with `--example`, or the real input.
+pub fn load_input(day_nb: i8, args: &cli::Args) -> Result<String, String> {
+    if let Some(file_path) = &args.input {
+        return fs::read_to_string(file_path)
+            .map_err(|e| format!("Unable to read the input file {file_path}: {e}"));
+    }
+
+    if args.example {
+        return utils::fetch_input::get_example_data(args.year, day_nb)
+            .ok_or(format!("No example found for day{day_nb}"));
+    }
+
+    utils::fetch_input::get_input_data(args.year, day_nb, args.quiet)
+}
+
+/// Loads the input of each day and gives it to `action`. Days without input are skipped
+/// with a notice when `skip_missing_input` is set, and are failures given to `on_load_error` otherwise.
+fn for_each_input(
+    days: &[&'static dyn utils::day::Solver],
+    args: &cli::Args,
+    skip_missing_input: bool,
+    mut on_load_error: impl FnMut(i8, &str),
+    mut action: impl FnMut(&'static dyn utils::day::Solver, String) -> Result<(), String>
+) -> bool {
+    let mut success = true;
+    for day in days {
+        let day_nb = day.get_nb();
+        let input = match load_input(day_nb, args) {
+            Ok(input) => input,
+            Err(message) if skip_missing_input => {
+                eprintln!("Skipping day{day_nb}: {message}");
+                continue;
+            },
+            Err(message) => {
+                on_load_error(day_nb, &message);
+                success &= report(Err(message));
+                continue;
+            }
+        };
+
+        success &= report(action(*day, input));
+    }
+    success
+}
+
+/// The days given on the command line, or all the days of the year when there is none.
+pub fn select_days(args: &cli::Args) -> Result<Vec<&'static dyn utils::day::Solver>, String> {
+    match &args.days {
+        None => Ok(day_factory::days_of_year(args.year).iter()
+            .map(|entry| entry.solver)
+            .collect()),
+        Some(day_nbs) => day_nbs.iter()
+            .map(|day_nb| day_factory::create_day(args.year, *day_nb)
+                .ok_or(format!("The day{day_nb} doesn't exist yet"))
+            )
+            .collect()
+    }
+}
+
+/// An error result for each part asked for, so the days that couldn't run are in the report.
+fn failed_parts(day_nb: i8, message: &str, args: &cli::Args) -> Vec<report::PartResult> {
+    args.part.map_or(vec![1, 2], |part| vec![part]).into_iter()
+        .map(|part| report::PartResult::new(args.year, day_nb, part, Err(message.to_string()), Duration::ZERO))
+        .collect()
+}
+
+/// Runs the days, in parallel with `--jobs`, then prints their results in the format asked for.
+fn run_days(days: &[&'static dyn utils::day::Solver], args: &cli::Args, skip_missing_input: bool) -> bool {
+    if let Some(jobs) = args.jobs {
+        return run_days_in_parallel(days, args, skip_missing_input, jobs);
+    }
+
+    let mut results = vec![];
+    let mut unloaded = vec![];
+    let success = for_each_input(
+        days,
+        args,
+        skip_missing_input,
+        |day_nb, message| unloaded.extend(failed_parts(day_nb, message, args)),
+        |day, input| run_parts(day, input, args, &mut results)
+    );
+    results.extend(unloaded);
+    results.sort_by_key(|result| (result.day, result.part));
+    print_report(&results, args) && success
+}
+
+/// A part solved by `solve_day`, and how long it took.
+struct SolvedPart {
+    part: i8,
+    result: Result<utils::answer::Answer, String>,
+    elapsed: Duration
+}
+
+/// Parses the input of a day, then runs its parts in parallel. Gives the time spent parsing with the parts.
+fn solve_day(
+    day: &'static dyn utils::day::Solver,
+    input: &str,
+    parts: &[i8],
+    timeout: Option<Duration>
+) -> (Duration, Result<Vec<SolvedPart>, String>) {
+    let start = Instant::now();
+    let parsed = utils::guard::parse_guarded(day, input);
+    let parse_time = start.elapsed();
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            return (parse_time, Err(format!("Error while parsing the input of day {}: {}", day.get_nb(), error_chain(&error))));
+        }
+    };
+
+    let solved = parts.par_iter()
+        .map(|part| {
+            let start = Instant::now();
+            let result = utils::guard::run_part_guarded(day, *part, &parsed, timeout).map_err(|error| error_chain(&error));
+            SolvedPart { part: *part, result, elapsed: start.elapsed() }
+        })
+        .collect();
+    (parse_time, Ok(solved))
+}
+
+/// Loads all the inputs first, then solves the days and their parts on `jobs` threads.
+/// The answers are printed in day order once everything is solved.
+fn run_days_in_parallel(days: &[&'static dyn utils::day::Solver], args: &cli::Args, skip_missing_input: bool, jobs: usize) -> bool {
+    let mut inputs = vec![];
+    let mut unloaded = vec![];
+    let mut success = for_each_input(
+        days,
+        args,
+        skip_missing_input,
+        |day_nb, message| unloaded.extend(failed_parts(day_nb, message, args)),
+        |day, input| {
+            inputs.push((day, input));
+            Ok(())
+        }
+    );
+    let pool = match rayon::ThreadPoolBuilder::new().num_threads(jobs).build() {
+        Ok(pool) => pool,
+        Err(e) => return report(Err(format!("Unable to start {jobs} threads: {e}")))
+    };
+    let parts: Vec<i8> = args.part.map_or(vec![1, 2], |part| vec![part]);
+
+    let start = Instant::now();
+    let solved_days: Vec<(Duration, Result<Vec<SolvedPart>, String>)> = pool.install(|| inputs.par_iter()
+        .map(|(day, input)| solve_day(*day, input, &parts, args.timeout))
+        .collect());
+    let wall_time = start.elapsed();
+
+    let mut results = vec![];
+    let mut cpu_time = Duration::ZERO;
+    for ((day, _), (parse_time, solved)) in inputs.iter().zip(solved_days) {
+        let day_nb = day.get_nb();
+        cpu_time += parse_time;
+        let solved_parts = match solved {
+            Ok(solved_parts) => solved_parts,
+            Err(message) => {
+                results.extend(failed_parts(day_nb, &message, args));
+                success &= report(Err(message));
+                continue;
+            }
+        };
+
+        let mut nb_failed = 0;
+        for SolvedPart { part, result, elapsed } in solved_parts {
+            cpu_time += elapsed;
+            if !record_part(day_nb, part, result, elapsed, args, &mut results) {
+                nb_failed += 1;
+            }
+        }
+        if nb_failed > 0 {
+            success &= report(Err(format!("{nb_failed} part(s) of day{day_nb} failed")));
+        }
+    }
+    results.extend(unloaded);
+    results.sort_by_key(|result| (result.day, result.part));
+
+    if !args.quiet {
+        println!("Solved {} day(s) on {jobs} thread(s) in {wall_time:.2?}, for {cpu_time:.2?} of summed parse and part times", inputs.len());
+    }
+    print_report(&results, args) && success
+}
+
+/// Prints the answer or the error of a part, and adds it to the results. Tells whether the part succeeded.
+fn record_part(
+    day_nb: i8,
+    part: i8,
+    result: Result<utils::answer::Answer, String>,
+    elapsed: Duration,
+    args: &cli::Args,
+    results: &mut Vec<report::PartResult>
+) -> bool {
+    let outcome = match result {
+        Ok(answer) => {
+            if args.format == report::OutputFormat::Text {
+                print_answer(day_nb, part, &answer, (!args.quiet).then_some(elapsed));
+            }
+            Ok(answer.to_string())
+        },
+        Err(message) => {
+            eprintln!("Error during day {day_nb} part {part}: {message}");
+            Err(message)
+        }
+    };
+    let succeeded = outcome.is_ok();
+    results.push(report::PartResult::new(args.year, day_nb, part, outcome, elapsed));
+    succeeded
+}
+
+/// Prints the results in the format asked for, unless it is the text already printed while running.
+fn print_report(results: &[report::PartResult], args: &cli::Args) -> bool {
+    let output = match args.format {
+        report::OutputFormat::Text => return true,
+        report::OutputFormat::Json => report::to_json_lines(results),
+        report::OutputFormat::Markdown => Ok(report::to_markdown(results)),
+        report::OutputFormat::Junit => Ok(report::to_junit(results))
+    };
+    match output {
+        Ok(output) => print!("{output}"),
+        Err(message) => return report(Err(message))
+    }
+    true
+}
+
+fn run_selected_days(args: &cli::Args) -> bool {
+    match select_days(args) {
+        Ok(days) => run_days(&days, args, false),
+        Err(message) => report(Err(message))
+    }
+}
+
+fn run_all_days(args: &cli::Args) -> bool {
+    if !args.quiet {
+        println!("Run all days!!!");
+    }
+
+    match select_days(args) {
+        Ok(days) => run_days(&days, args, true),
+        Err(message) => report(Err(message))
+    }
+}
+
+fn print_bench(year: i16, bench: &bench::DayBench, baseline: Option<&bench::Baseline>, threshold: f64) -> bool {
+    let mut no_regression = true;
+    for (phase, stats) in &bench.phases {
+        let stats = match stats {
+            Ok(stats) => stats,
+            Err(error) => {
+                println!("{:>3}  {phase}  failed: {}", bench.day, error_chain(error));
+                continue;
+            }
+        };
+
+        let comparison = baseline
+            .and_then(|baseline| baseline.median(year, bench.day, *phase))
+            .map(|base| bench::compare(stats.median, base, threshold));
+        if let Some(bench::Comparison::Regression(_)) = comparison {
+            no_regression = false;
+        }
+
+        println!(
+            "{:>3}  {phase}  {:>10}  {:>10}  {:>10}  {:>10}  {}",
+            bench.day,
+            format!("{:.2?}", stats.min),
+            format!("{:.2?}", stats.median),
+            format!("{:.2?}", stats.mean),
+            format!("{:.2?}", stats.stddev),
+            comparison.map(|c| c.to_string()).unwrap_or_default()
+        );
+    }
+    no_regression
+}
+
+fn bench_days(args: &cli::Args) -> bool {
+    let days = match select_days(args) {
+        Ok(days) => days,
+        Err(message) => return report(Err(message))
+    };
+    let baseline = match &args.bench.baseline {
+        Some(file_path) => match bench::Baseline::load(file_path) {
+            Ok(baseline) => Some(baseline),
+            Err(message) => return report(Err(message))
+        },
+        None => None
+    };
+    let parts: Vec<i8> = args.part.map_or(vec![1, 2], |part| vec![part]);
+
+    println!("Day  Phase         min      median        mean      stddev");
+    let mut new_baseline = bench::Baseline::default();
+    let mut total = Duration::ZERO;
+    let mut no_regression = true;
+    let success = for_each_input(&days, args, args.days.is_none(), |_, _| {}, |day, input| {
+        let day_bench = bench::bench_day(day, &input, &parts, args.bench.runs, args.bench.warmup);
+        no_regression &= print_bench(args.year, &day_bench, baseline.as_ref(), args.bench.threshold);
+        total += day_bench.phases.iter()
+            .filter_map(|(_, stats)| stats.as_ref().ok())
+            .map(|stats| stats.median)
+            .sum::<Duration>();
+        new_baseline.record(args.year, &day_bench);
+
+        let nb_failed = day_bench.phases.iter().filter(|(_, stats)| stats.is_err()).count();
+        if nb_failed == 0 { Ok(()) } else { Err(format!("{nb_failed} phase(s) of day{} failed", day.get_nb())) }
+    });
+    println!("Total of the medians for {}: {total:.2?}", args.year);
+
+    if let Some(file_path) = &args.bench.save_baseline {
+        if !report(new_baseline.save(file_path)) {
+            return false;
+        }
+        println!("Baseline saved into {file_path}");
+    }
+    if !no_regression {
+        eprintln!("Some phases are more than {}% slower than the baseline", args.bench.threshold);
+    }
+    success && no_regression
+}
+
+/// Runs the parts of a day and checks their answers. Unknown answers are recorded in `book` when `accept` is set.
+fn verify_day(
+    day: &'static dyn utils::day::Solver,
+    input: String,
+    args: &cli::Args,
+    book: &mut answers::AnswerBook,
+    tally: &mut answers::Tally
+) -> Result<(), String> {
+    let day_nb = day.get_nb();
+    let parsed = parse_input(day, &input, args)?;
+    let parts: Vec<i8> = args.part.map_or(vec![1, 2], |part| vec![part]);
+
+    let mut nb_failed = 0;
+    for part in parts {
+        let answer = match utils::guard::run_part_guarded(day, part, &parsed, args.timeout) {
+            Ok(answer) => answer,
+            Err(error) => {
+                println!("Day {day_nb} part {part}: ERROR {}", error_chain(&error));
+                nb_failed += 1;
+                continue;
+            }
+        };
+
+        let verdict = book.check(args.year, day_nb, part, &answer);
+        tally.add(&verdict);
+        if let answers::Verdict::Fail { .. } = verdict {
+            nb_failed += 1;
+        }
+        let accepted = if verdict == answers::Verdict::Unknown && args.verify.accept {
+            book.record(args.year, day_nb, part, &answer);
+            " -> accepted"
+        } else {
+            ""
+        };
+        if answer.is_multiline() {
+            println!("Day {day_nb} part {part}: {verdict}{accepted}\n{answer}");
+        } else {
+            println!("Day {day_nb} part {part}: {verdict} {answer}{accepted}");
+        }
+    }
+
+    if nb_failed == 0 { Ok(()) } else { Err(format!("{nb_failed} part(s) of day{day_nb} didn't pass")) }
+}
+
+fn verify_days(args: &cli::Args) -> bool {
+    let days = match select_days(args) {
+        Ok(days) => days,
+        Err(message) => return report(Err(message))
+    };
+    let answers_file = &args.verify.answers_file;
+    let mut book = match answers::AnswerBook::load(answers_file) {
+        Ok(book) => book,
+        Err(message) => return report(Err(message))
+    };
+
+    let mut tally = answers::Tally::default();
+    let success = for_each_input(&days, args, args.days.is_none(), |_, _| {}, |day, input| {
+        verify_day(day, input, args, &mut book, &mut tally)
+    });
+    println!("{tally}");
+
+    if args.verify.accept {
+        if !report(book.save(answers_file)) {
+            return false;
+        }
+        println!("Answers saved into {answers_file}");
+    }
+    success
+}
+
+/// Reads a session cookie from the standard input, checks it and saves it for the next runs.
+fn login(args: &cli::Args) -> Result<(), String> {
+    println!("Paste the value of the session cookie of adventofcode.com:");
+    let mut token = String::new();
+    io::stdin().read_line(&mut token)
+        .map_err(|e| format!("Unable to read the session: {e}"))?;
+
+    let token = utils::session::normalize(&token).ok_or("The session is empty".to_string())?;
+    let session = utils::session::Session { token, source: utils::session::SessionSource::Stdin };
+    let user = utils::session::whoami(utils::submit::DEFAULT_BASE_URL, args.year, &session)
+        .map_err(|message| format!("The session was not saved: {message}"))?;
+    let path = utils::session::save(&session.token, &user)?;
+    println!("Logged in as {user}, session saved into {}", path.display());
+    Ok(())
+}
+
+fn whoami(args: &cli::Args) -> Result<(), String> {
+    let session = utils::session::resolve()?;
+    let user = utils::session::whoami(utils::submit::DEFAULT_BASE_URL, args.year, &session)?;
+    println!("Logged in as {user}, with the session from {}", session.source);
+    Ok(())
+}
+
+/// The puzzle page of the day: the given file, the cached copy if it has both parts, or a new download.
+fn load_page(cache: &utils::cache::InputCache, day_nb: i8, args: &cli::Args) -> Result<String, String> {
+    if let Some(file_path) = &args.examples.page {
+        return fs::read_to_string(file_path)
+            .map_err(|e| format!("Unable to read the puzzle page {file_path}: {e}"));
+    }
+    if let Some(page) = cache.read_page(args.year, day_nb).filter(|page| utils::examples::has_part2(page)) {
+        return Ok(page);
+    }
+
+    let page = utils::fetch_input::default_fetcher()?.fetch_page(args.year, day_nb)?;
+    cache.write_page(args.year, day_nb, &page)?;
+    Ok(page)
+}
+
+fn extract_examples(args: &cli::Args) -> Result<(), String> {
+    let cache = utils::cache::InputCache::resolve()?;
+    let dir = utils::examples::examples_dir(args.year);
+    let mut nb_failed = 0;
+    for day in select_days(args)? {
+        let day_nb = day.get_nb();
+        if !args.examples.force && dir.join(format!("day{day_nb}.json")).exists() {
+            println!("Skipping day{day_nb}: its examples are already saved, use --force to replace them");
+            continue;
+        }
+
+        let examples = match load_page(&cache, day_nb, args).map(|page| utils::examples::extract_examples(&page)) {
+            Ok(examples) if !examples.is_empty() => examples,
+            Ok(_) => {
+                eprintln!("No example found in the puzzle page of day{day_nb}");
+                nb_failed += 1;
+                continue;
+            },
+            Err(message) => {
+                eprintln!("Unable to get the puzzle page of day{day_nb}: {message}");
+                nb_failed += 1;
+                continue;
+            }
+        };
+
+        save_examples(&dir, day_nb, &examples)?;
+    }
+
+    if nb_failed == 0 { Ok(()) } else { Err(format!("No examples for {nb_failed} day(s)")) }
+}
+
+fn save_examples(dir: &Path, day_nb: i8, examples: &[utils::examples::Example]) -> Result<(), String> {
+    utils::examples::save_examples(dir, day_nb, examples)?;
+    for (idx, example) in examples.iter().enumerate() {
+        let expected = |answer: &Option<String>| answer.clone().unwrap_or("?".to_string());
+        println!("Day {day_nb} example {}: part 1 {}, part 2 {}", idx + 1, expected(&example.part1), expected(&example.part2));
+    }
+    Ok(())
+}
+
+/// Creates the module of a new day, named after its puzzle when `--fetch` also gets its page, input and examples.
+fn new_day(args: &cli::Args) -> Result<(), String> {
+    let Some([day_nb]) = args.days.as_deref() else {
+        return Err("new needs a single day".to_string());
+    };
+    if day_factory::find_day(args.year, *day_nb).is_some() {
+        return Err(format!("The day{day_nb} of {} is already registered", args.year));
+    }
+
+    let page = if args.new.fetch {
+        let cache = utils::cache::InputCache::resolve()?;
+        load_page(&cache, *day_nb, args)
+            .map_err(|message| eprintln!("Unable to get the puzzle page of day{day_nb}: {message}"))
+            .ok()
+    } else {
+        None
+    };
+    let title = page.as_deref()
+        .and_then(utils::examples::puzzle_title)
+        .unwrap_or(format!("Day {day_nb}"));
+
+    for path in scaffold::scaffold_day(Path::new("./src"), args.year, *day_nb, &title)? {
+        println!("Written {}", path.display());
+    }
+    if !args.new.fetch {
+        return Ok(());
+    }
+
+    let mut nb_failed = 0;
+    if let Err(message) = utils::fetch_input::get_input_data(args.year, *day_nb, args.quiet) {
+        eprintln!("Unable to get the input of day{day_nb}: {message}");
+        nb_failed += 1;
+    }
+    let examples = page.as_deref().map(utils::examples::extract_examples).unwrap_or_default();
+    if examples.is_empty() {
+        eprintln!("No example saved for day{day_nb}");
+        nb_failed += 1;
+    } else {
+        save_examples(&utils::examples::examples_dir(args.year), *day_nb, &examples)?;
+    }
+
+    if nb_failed == 0 { Ok(()) } else { Err(format!("The day{day_nb} was created, but {nb_failed} download(s) failed")) }
+}
+
+fn manage_cache(action: cli::CacheAction, args: &cli::Args) -> Result<(), String> {
+    let cache = utils::cache::InputCache::resolve()?;
+    match action {
+        cli::CacheAction::List => {
+            for input in cache.list()? {
+                println!("{} day {:>2}  {:>8} bytes  {}", input.year, input.day, input.size, input.path.display());
+            }
+        },
+        cli::CacheAction::Clear => {
+            let removed = cache.clear(args.year, args.days.as_deref())?;
+            println!("{} input(s) of {} removed from the cache", removed.len(), args.year);
+        },
+        cli::CacheAction::Refresh => {
+            let days = select_days(args)?;
+            let mut nb_failed = 0;
+            for day in days {
+                if let Err(message) = utils::fetch_input::download_input(&cache, args.year, day.get_nb(), args.quiet) {
+                    eprintln!("Unable to refresh the input of day{}: {message}", day.get_nb());
+                    nb_failed += 1;
+                }
+            }
+            if nb_failed > 0 {
+                return Err(format!("{nb_failed} input(s) couldn't be refreshed"));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Runs the parts of `day` on `input`, printing a line for each so the watch loop can read it.
+fn print_part_runs(
+    day: &'static dyn utils::day::Solver,
+    input: &str,
+    label: &str,
+    parts: Vec<(i8, Option<String>)>,
+    timeout: Option<Duration>
+) {
+    let parsed = utils::guard::parse_guarded(day, input);
+    for (part, expected) in parts {
+        let start = Instant::now();
+        let outcome = match &parsed {
+            Ok(parsed) => utils::guard::run_part_guarded(day, part, parsed, timeout)
+                .map(|answer| answer.to_string())
+                .map_err(|error| error_chain(&error)),
+            Err(error) => Err(format!("Error while parsing: {}", error_chain(error)))
+        };
+        let run = watch::PartRun { label: label.to_string(), part, outcome, expected, elapsed: start.elapsed() };
+        println!("{}", run.to_line());
+    }
+}
+
+/// Runs the examples that have an expected answer, then the input.
+fn watch_once(args: &cli::Args) -> Result<(), String> {
+    let Some([day_nb]) = args.days.as_deref() else {
+        return Err("watch needs a single day".to_string());
+    };
+    let day = day_factory::create_day(args.year, *day_nb)
+        .ok_or(format!("The day{day_nb} doesn't exist yet"))?;
+    let wanted = |part: i8| args.part.is_none_or(|wanted| wanted == part);
+
+    for (file, example) in utils::examples::load_examples(&utils::examples::examples_dir(args.year), *day_nb)? {
+        let parts: Vec<(i8, Option<String>)> = [(1, example.part1), (2, example.part2)].into_iter()
+            .filter(|(part, expected)| expected.is_some() && wanted(*part))
+            .collect();
+        print_part_runs(day, &example.input, file.trim_end_matches(".txt"), parts, args.timeout);
+    }
+
+    let input = load_input(*day_nb, args)?;
+    let parts = [1, 2].into_iter().filter(|part| wanted(*part)).map(|part| (part, None)).collect();
+    print_part_runs(day, &input, watch::INPUT_LABEL, parts, args.timeout);
+    Ok(())
+}
+
+/// Rebuilds then runs `watch --once` in a child process, so the last changes of the source are taken.
+fn run_watch_step(args: &cli::Args, day_nb: i8) -> Result<Vec<watch::PartRun>, String> {
+    let mut command = process::Command::new(env::var_os("CARGO").unwrap_or("cargo".into()));
+    command.args(["run", "--quiet"]);
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    // Rebuilt with the features of this build, so the reruns behave like it
+    command.arg("--no-default-features");
+    let features: Vec<&str> = [("fetch", cfg!(feature = "fetch")), ("tui", cfg!(feature = "tui"))].into_iter()
+        .filter_map(|(feature, enabled)| enabled.then_some(feature))
+        .collect();
+    if !features.is_empty() {
+        command.args(["--features", &features.join(",")]);
+    }
+    command.args(["--", "watch", &day_nb.to_string(), "--once", "--quiet", "--year", &args.year.to_string()]);
+    if let Some(part) = args.part {
+        command.args(["--part", &part.to_string()]);
+    }
+    if let Some(file_path) = &args.input {
+        command.args(["--input", file_path]);
+    }
+    if let Some(timeout) = args.timeout {
+        command.args(["--timeout", &timeout.as_secs_f64().to_string()]);
+    }
+
+    let output = command.stderr(Stdio::inherit()).output()
+        .map_err(|e| format!("Unable to run cargo: {e}"))?;
+    let runs: Vec<watch::PartRun> = String::from_utf8_lossy(&output.stdout).lines()
+        .filter_map(|line| {
+            let run = watch::PartRun::from_line(line);
+            if run.is_none() {
+                println!("{line}");
+            }
+            run
+        })
+        .collect();
+    if runs.is_empty() && !output.status.success() {
+        return Err("The build or the run failed".to_string());
+    }
+    Ok(runs)
+}
+
+/// Runs the day each time its source, input or examples change, showing what changed since the previous run.
+fn watch_day(args: &cli::Args) -> Result<(), String> {
+    if args.watch.once {
+        return watch_once(args);
+    }
+    let Some([day_nb]) = args.days.as_deref() else {
+        return Err("watch needs a single day".to_string());
+    };
+    let input_path = match &args.input {
+        Some(file_path) => PathBuf::from(file_path),
+        None => utils::cache::InputCache::resolve()?.input_path(args.year, *day_nb)
+    };
+    let examples_dir = utils::examples::examples_dir(args.year);
+    let times = || watch::modification_times(&watch::watched_files(Path::new("./src"), &examples_dir, args.year, *day_nb, &input_path));
+
+    let mut previous: BTreeMap<(String, i8), watch::PartRun> = BTreeMap::new();
+    let mut last_times = None;
+    loop {
+        if last_times.as_ref() != Some(&times()) {
+            println!("Rebuilding and running day{day_nb}...");
+            match run_watch_step(args, *day_nb) {
+                Ok(runs) => for run in runs {
+                    let key = (run.label.clone(), run.part);
+                    println!("{}", watch::describe(&run, previous.get(&key)));
+                    previous.insert(key, run);
+                },
+                Err(message) => eprintln!("{message}")
+            }
+            // Taken after the run, so downloading the input doesn't trigger another one
+            last_times = Some(times());
+            println!("Watching day{day_nb} for changes, press Ctrl-C to stop");
+        }
+        thread::sleep(Duration::from_millis(args.watch.interval_ms));
+    }
+}
+
+#[cfg(feature = "tui")]
+fn show_dashboard(args: &cli::Args) -> Result<(), String> {
+    crate::tui::run_dashboard(args)
+}
+
+#[cfg(not(feature = "tui"))]
+fn show_dashboard(_args: &cli::Args) -> Result<(), String> {
+    Err("The tui command needs the tui feature: cargo run --features tui -- tui".to_string())
+}
+
+/// Runs the given part of the given day and posts its answer, unless it is already known to be right or wrong.
+fn submit_answer(args: &cli::Args) -> Result<(), String> {
+    let (Some([day_nb]), Some(part)) = (args.days.as_deref(), args.part) else {
+        return Err("submit needs a single day and a part".to_string());
+    };
+    let day = day_factory::create_day(args.year, *day_nb)
+        .ok_or(format!("The day{day_nb} doesn't exist yet"))?;
+    let session = utils::session::resolve()?;
+
+    let input = load_input(*day_nb, args)?;
+    let parsed = parse_input(day, &input, args)?;
+    let answer = utils::guard::run_part_guarded(day, part, &parsed, args.timeout)
+        .map_err(|error| format!("Error during day {day_nb} part {part}: {}", error_chain(&error)))?;
+    print_answer(*day_nb, part, &answer, None);
+
+    let mut book = answers::AnswerBook::load(&args.verify.answers_file)?;
+    if let Some(known) = book.get(args.year, *day_nb, part) {
+        return Err(format!("Not submitted, the answer of day {day_nb} part {part} is already known: {known}"));
+    }
+    let log_file = &args.submit.log_file;
+    let mut log = answers::SubmissionLog::load(log_file)?;
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)
+        .map_err(|e| format!("The clock is before 1970: {e}"))?
+        .as_secs();
+    if let Some(refusal) = log.refusal(args.year, *day_nb, part, &answer.to_string(), now) {
+        return Err(format!("Not submitted: {refusal}"));
+    }
+
+    let client = utils::submit::SubmitClient::new(utils::submit::DEFAULT_BASE_URL, session);
+    let outcome = client.submit(args.year, *day_nb, part, &answer.to_string())?;
+    println!("{outcome}");
+
+    log.record(args.year, *day_nb, part, answers::Submission { answer: answer.to_string(), outcome: outcome.clone(), at: now });
+    log.save(log_file)?;
+    if outcome != utils::submit::Outcome::Right {
+        return Err(format!("The answer of day {day_nb} part {part} was not accepted"));
+    }
+    book.record(args.year, *day_nb, part, &answer);
+    book.save(&args.verify.answers_file)
+}
+
+/// Parses the input once, printing how long it took.
+fn parse_input(day: &dyn utils::day::Solver, input: &str, args: &cli::Args) -> Result<Arc<utils::day::ParsedInput>, String> {
+    let day_nb = day.get_nb();
+    let start = Instant::now();
+    let parsed = utils::guard::parse_guarded(day, input)
+        .map_err(|error| format!("Error while parsing the input of day {day_nb}: {}", error_chain(&error)))?;
+    if !args.quiet {
+        println!("Input of day{day_nb} parsed in {:.2?}", start.elapsed());
+    }
+    Ok(parsed)
+}
+
+/// Runs the parts asked for, printing their answers in the text format. Every part gets a result, even when the parsing fails.
+pub fn run_parts(
+    day: &'static dyn utils::day::Solver,
+    input: String,
+    args: &cli::Args,
+    results: &mut Vec<report::PartResult>
+) -> Result<(), String> {
+    let day_nb = day.get_nb();
+    let parts: Vec<i8> = args.part.map_or(vec![1, 2], |part| vec![part]);
+    if !args.quiet {
+        println!("Start of day{day_nb}");
+    }
+
+    let parsed = match parse_input(day, &input, args) {
+        Ok(parsed) => parsed,
+        Err(message) => {
+            results.extend(failed_parts(day_nb, &message, args));
+            return Err(message);
+        }
+    };
+    let mut nb_failed = 0;
+    for part in parts {
+        if !args.quiet {
+            println!("Start of part{part}!");
+        }
+        let start = Instant::now();
+        let part_result = utils::guard::run_part_guarded(day, part, &parsed, args.timeout).map_err(|error| error_chain(&error));
+        let elapsed = start.elapsed();
+        if !record_part(day_nb, part, part_result, elapsed, args, results) {
+            nb_failed += 1;
+        }
+    }
+
+    if nb_failed == 0 { Ok(()) } else { Err(format!("{nb_failed} part(s) of day{day_nb} failed")) }
+}
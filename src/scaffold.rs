@@ -0,0 +1,137 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The module of a new day, with a stub for each part. It registers itself with `register_day!`.
+pub fn day_template(year: i16, day: i8, title: &str) -> String {
+    let title = title.replace('\\', "\\\\").replace('"', "\\\"");
+    format!(r#"use crate::day_factory::register_day;
+use crate::utils::answer::Answer;
+use crate::utils::day::Day;
+use crate::utils::day_error::DayError;
+
+pub struct Day{day};
+
+register_day!(Day{day}, year: {year}, title: "{title}", tags: []);
+
+impl Day for Day{day} {{
+    type Input = Vec<String>;
+
+    fn get_nb(&self) -> i8 {{
+        {day}
+    }}
+
+    fn parse(&self, input: &str) -> Result<Self::Input, DayError> {{
+        Ok(input.lines().map(str::to_string).collect())
+    }}
+
+    fn part1(&self, _input: &Self::Input) -> Result<Answer, DayError> {{
+        Err(DayError::Unsolved {{ part: 1 }})
+    }}
+
+    fn part2(&self, _input: &Self::Input) -> Result<Answer, DayError> {{
+        Err(DayError::Unsolved {{ part: 2 }})
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use crate::days::y{year}::day{day}::Day{day};
+    use crate::utils::day::Day;
+
+    #[test]
+    fn it_test_parse() {{
+        let input = Day{day}.parse("first\nsecond").expect("There should be a parsed input");
+
+        assert_eq!(input.len(), 2);
+    }}
+}}
+"#)
+}
+
+/// The number at the end of a module name, like the 12 of `day12`, to sort them in order.
+fn module_number(line: &str) -> u32 {
+    let name = line.trim().trim_start_matches("pub mod ").trim_end_matches(';');
+    name.trim_start_matches(|c: char| !c.is_ascii_digit()).parse().unwrap_or_default()
+}
+
+/// Declares `pub mod <name>;` in `mod_file`, keeping the declarations sorted. Creates the file if needed.
+/// Tells whether the declaration was added, `false` meaning it was already there.
+fn declare_module(mod_file: &Path, name: &str) -> Result<bool, String> {
+    let content = match fs::read_to_string(mod_file) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(format!("Unable to read {}: {e}", mod_file.display()))
+    };
+    let declaration = format!("pub mod {name};");
+    let mut lines: Vec<&str> = content.lines().filter(|line| !line.trim().is_empty()).collect();
+    if lines.iter().any(|line| line.trim() == declaration) {
+        return Ok(false);
+    }
+
+    lines.push(&declaration);
+    lines.sort_by_key(|line| module_number(line));
+    fs::write(mod_file, lines.join("\n"))
+        .map_err(|e| format!("Unable to write {}: {e}", mod_file.display()))?;
+    Ok(true)
+}
+
+/// Writes `<src_dir>/days/y<YEAR>/day<N>.rs` and declares it in the year module, and the year in `days.rs`.
+/// Refuses to replace a day that already exists. Gives the written files.
+pub fn scaffold_day(src_dir: &Path, year: i16, day: i8, title: &str) -> Result<Vec<PathBuf>, String> {
+    let days_file = src_dir.join("days.rs");
+    if !days_file.exists() {
+        return Err(format!("No {}, the command should be run from the root of the crate", days_file.display()));
+    }
+    let year_dir = src_dir.join("days").join(format!("y{year}"));
+    let day_file = year_dir.join(format!("day{day}.rs"));
+    if day_file.exists() {
+        return Err(format!("The day{day} of {year} already exists in {}", day_file.display()));
+    }
+
+    fs::create_dir_all(&year_dir)
+        .map_err(|e| format!("Unable to create the directory {}: {e}", year_dir.display()))?;
+    fs::write(&day_file, day_template(year, day, title))
+        .map_err(|e| format!("Unable to write {}: {e}", day_file.display()))?;
+
+    let mut paths = vec![day_file];
+    let year_file = src_dir.join("days").join(format!("y{year}.rs"));
+    if declare_module(&year_file, &format!("day{day}"))? {
+        paths.push(year_file);
+    }
+    if declare_module(&days_file, &format!("y{year}"))? {
+        paths.push(days_file);
+    }
+    Ok(paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+
+    use crate::scaffold::scaffold_day;
+
+    #[test]
+    fn it_test_scaffold_day() {
+        let src_dir = env::temp_dir().join(format!("aoc_scaffold_{}", std::process::id()));
+        fs::create_dir_all(src_dir.join("days")).expect("The directory should be created");
+        fs::write(src_dir.join("days.rs"), "pub mod y2025;").expect("The file should be written");
+        fs::write(src_dir.join("days").join("y2025.rs"), "pub mod day1;\npub mod day2;\npub mod day10;").expect("The file should be written");
+
+        let written = scaffold_day(&src_dir, 2025, 3, "Lobby").expect("The day should be created");
+        let other_year = scaffold_day(&src_dir, 2024, 1, "Historian \"Hysteria\"").expect("The day should be created");
+        let again = scaffold_day(&src_dir, 2025, 3, "Lobby");
+        let year_mods = fs::read_to_string(src_dir.join("days").join("y2025.rs")).expect("The file should be read");
+        let days_mods = fs::read_to_string(src_dir.join("days.rs")).expect("The file should be read");
+        let day_code = fs::read_to_string(src_dir.join("days").join("y2024").join("day1.rs")).expect("The file should be read");
+        fs::remove_dir_all(&src_dir).expect("The directory should be removed");
+
+        assert_eq!(written.len(), 2);
+        assert_eq!(other_year.len(), 3);
+        assert!(again.expect_err("There should be an error").contains("already exists"));
+        assert_eq!(year_mods, "pub mod day1;\npub mod day2;\npub mod day3;\npub mod day10;");
+        assert_eq!(days_mods, "pub mod y2024;\npub mod y2025;");
+        assert!(day_code.contains(r#"register_day!(Day1, year: 2024, title: "Historian \"Hysteria\"", tags: []);"#));
+    }
+}
@@ -0,0 +1,227 @@
+//! The `tui` command: a dashboard of the calendar of a year in the terminal.
+
+use std::env;
+use std::io::{self, Stdout};
+use std::panic::{self, PanicHookInfo};
+use std::process;
+use std::sync::Mutex;
+use std::thread;
+
+use ratatui::backend::CrosstermBackend;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::crossterm::execute;
+use ratatui::crossterm::terminal::{self, EnterAlternateScreen, LeaveAlternateScreen};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Cell, Paragraph, Row, Table, TableState};
+use ratatui::{Frame, Terminal};
+
+use crate::answers::AnswerBook;
+use crate::cli;
+use crate::dashboard::{timing_bar, Dashboard, PartState, PartStatus};
+use crate::utils;
+
+const KEYS: &str = "↑/↓ select  r run  e examples  o open the input  f fetch the input again  q quit";
+const BAR_WIDTH: usize = 20;
+
+type PanicHook = Box<dyn Fn(&PanicHookInfo<'_>) + Sync + Send + 'static>;
+
+/// The last panic, printed once the terminal is restored if it was the dashboard's own.
+static LAST_PANIC: Mutex<Option<String>> = Mutex::new(None);
+
+/// Owns the terminal while the dashboard is shown, and gives it back on drop, even on a panic.
+struct Screen {
+    terminal: Terminal<CrosstermBackend<Stdout>>,
+    previous_hook: Option<PanicHook>
+}
+
+impl Screen {
+    fn enter() -> Result<Screen, String> {
+        start_terminal()?;
+        let terminal = Terminal::new(CrosstermBackend::new(io::stdout()))
+            .map_err(|e| format!("Unable to use the terminal: {e}"))?;
+        // The panics of the solvers are caught and shown in the table, they must not be printed over it
+        let previous_hook = panic::take_hook();
+        panic::set_hook(Box::new(|info| {
+            if let Ok(mut last) = LAST_PANIC.lock() {
+                *last = Some(info.to_string());
+            }
+        }));
+        Ok(Screen { terminal, previous_hook: Some(previous_hook) })
+    }
+
+    /// Gives the terminal back while `action` runs, for a pager for instance.
+    fn suspend<T>(&mut self, action: impl FnOnce() -> T) -> Result<T, String> {
+        stop_terminal();
+        let result = action();
+        start_terminal()?;
+        self.terminal.clear().map_err(|e| format!("Unable to clear the terminal: {e}"))?;
+        Ok(result)
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        stop_terminal();
+        if let Some(hook) = self.previous_hook.take() {
+            panic::set_hook(hook);
+        }
+        if thread::panicking() {
+            if let Some(message) = LAST_PANIC.lock().ok().and_then(|mut last| last.take()) {
+                eprintln!("{message}");
+            }
+        }
+    }
+}
+
+fn start_terminal() -> Result<(), String> {
+    terminal::enable_raw_mode().map_err(|e| format!("Unable to use the terminal: {e}"))?;
+    execute!(io::stdout(), EnterAlternateScreen).map_err(|e| format!("Unable to use the terminal: {e}"))
+}
+
+fn stop_terminal() {
+    let _ = terminal::disable_raw_mode();
+    let _ = execute!(io::stdout(), LeaveAlternateScreen);
+}
+
+fn status_style(status: PartStatus) -> Style {
+    match status {
+        PartStatus::NotImplemented => Style::new().fg(Color::DarkGray),
+        PartStatus::Pending => Style::new(),
+        PartStatus::AnswerKnown => Style::new().fg(Color::Cyan),
+        PartStatus::Answered => Style::new().fg(Color::Yellow),
+        PartStatus::Verified => Style::new().fg(Color::Green),
+        PartStatus::Failing => Style::new().fg(Color::Red).add_modifier(Modifier::BOLD)
+    }
+}
+
+fn part_cell(part: &PartState) -> Cell<'static> {
+    Cell::from(part.status.to_string()).style(status_style(part.status))
+}
+
+fn draw(frame: &mut Frame, dashboard: &Dashboard) {
+    let [table_area, detail_area, footer_area] = Layout::vertical([
+        Constraint::Min(5),
+        Constraint::Length(4),
+        Constraint::Length(2)
+    ]).areas(frame.area());
+
+    let max_time = dashboard.max_run_time().unwrap_or_default();
+    let rows: Vec<Row> = dashboard.rows.iter()
+        .map(|row| {
+            let time = row.last_run_time();
+            Row::new(vec![
+                Cell::from(format!("{:>2}", row.day)),
+                Cell::from(row.title),
+                part_cell(&row.parts[0]),
+                part_cell(&row.parts[1]),
+                Cell::from(row.examples.map(|(passed, total)| format!("{passed}/{total}")).unwrap_or_default()),
+                Cell::from(time.map(|time| format!("{time:.2?}")).unwrap_or_default()),
+                Cell::from(time.map(|time| timing_bar(time, max_time, BAR_WIDTH)).unwrap_or_default())
+            ])
+        })
+        .collect();
+    let widths = [
+        Constraint::Length(3),
+        Constraint::Length(26),
+        Constraint::Length(15),
+        Constraint::Length(15),
+        Constraint::Length(8),
+        Constraint::Length(10),
+        Constraint::Length(BAR_WIDTH as u16)
+    ];
+    let table = Table::new(rows, widths)
+        .header(Row::new(["Day", "Title", "Part 1", "Part 2", "Examples", "Time", ""]).style(Style::new().add_modifier(Modifier::BOLD)))
+        .block(Block::bordered().title(format!(" Advent of Code {} ", dashboard.year)))
+        .row_highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+    let mut state = TableState::default().with_selected(Some(dashboard.selected));
+    frame.render_stateful_widget(table, table_area, &mut state);
+
+    let selected = dashboard.selected_row();
+    let details: Vec<Line> = selected.parts.iter().enumerate()
+        .map(|(index, part)| {
+            let detail = part.detail.as_deref().unwrap_or("-").replace('\n', " / ");
+            Line::styled(format!("Part {}: {detail}", index + 1), status_style(part.status))
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(details).block(Block::bordered().title(format!(" Day {} ", selected.day))), detail_area);
+
+    frame.render_widget(Paragraph::new(vec![Line::from(dashboard.message.as_str()), Line::from(KEYS)]), footer_area);
+}
+
+/// Opens the cached input of the selected day in `$PAGER`, or `less`.
+fn open_input(screen: &mut Screen, year: i16, day_nb: i8) -> Result<String, String> {
+    let path = utils::cache::InputCache::resolve()?.input_path(year, day_nb);
+    if !path.exists() {
+        return Err(format!("The input of day{day_nb} isn't downloaded yet, press r or f"));
+    }
+    let pager = env::var("PAGER").unwrap_or("less".to_string());
+    let status = screen.suspend(|| process::Command::new(&pager).arg(&path).status())?
+        .map_err(|e| format!("Unable to run {pager}: {e}"))?;
+    Ok(format!("{pager} exited with {status}"))
+}
+
+fn refetch_input(year: i16, day_nb: i8) -> Result<String, String> {
+    let cache = utils::cache::InputCache::resolve()?;
+    utils::fetch_input::download_input(&cache, year, day_nb, true)?;
+    Ok(format!("Downloaded the input of day{day_nb} again"))
+}
+
+/// Shows the dashboard until `q` is pressed.
+pub fn run_dashboard(args: &cli::Args) -> Result<(), String> {
+    let book = AnswerBook::load(&args.verify.answers_file)?;
+    let mut dashboard = Dashboard::new(args.year, book);
+    dashboard.message = format!("Answers read from {}", args.verify.answers_file);
+    let mut screen = Screen::enter()?;
+
+    loop {
+        screen.terminal.draw(|frame| draw(frame, &dashboard))
+            .map_err(|e| format!("Unable to draw the dashboard: {e}"))?;
+        let Event::Key(key) = event::read().map_err(|e| format!("Unable to read the keyboard: {e}"))? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+
+        let day_nb = dashboard.selected_row().day;
+        let waiting = match key.code {
+            KeyCode::Char('r') => Some(format!("Running day{day_nb}...")),
+            KeyCode::Char('e') => Some(format!("Running the examples of day{day_nb}...")),
+            KeyCode::Char('f') => Some(format!("Downloading the input of day{day_nb}...")),
+            _ => None
+        };
+        if let Some(waiting) = &waiting {
+            dashboard.message = waiting.clone();
+            screen.terminal.draw(|frame| draw(frame, &dashboard))
+                .map_err(|e| format!("Unable to draw the dashboard: {e}"))?;
+        }
+
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+            KeyCode::Down | KeyCode::Char('j') => dashboard.select_next(),
+            KeyCode::Up | KeyCode::Char('k') => dashboard.select_previous(),
+            KeyCode::Char('r') => match utils::fetch_input::get_input_data(args.year, day_nb, true) {
+                Ok(input) => dashboard.run_selected(&input, args.timeout),
+                Err(message) => dashboard.message = message
+            },
+            KeyCode::Char('e') => match utils::examples::load_examples(&utils::examples::examples_dir(args.year), day_nb) {
+                Ok(examples) if examples.is_empty() => dashboard.message = format!("No example saved for day{day_nb}"),
+                Ok(examples) => dashboard.run_selected_examples(&examples, args.timeout),
+                Err(message) => dashboard.message = message
+            },
+            KeyCode::Char('o') => {
+                dashboard.message = open_input(&mut screen, args.year, day_nb).unwrap_or_else(|message| message);
+            },
+            KeyCode::Char('f') => {
+                dashboard.message = refetch_input(args.year, day_nb).unwrap_or_else(|message| message);
+            },
+            _ => {}
+        }
+        if waiting.is_some() {
+            // What was written to the standard error while running is wiped
+            screen.terminal.clear().map_err(|e| format!("Unable to clear the terminal: {e}"))?;
+        }
+    }
+}
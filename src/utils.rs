@@ -1,3 +1,16 @@
 pub mod fetch_input;
 pub mod day;
-pub mod day_error;
\ No newline at end of file
+pub mod day_error;
+pub mod answer;
+pub mod submit;
+pub mod config;
+pub mod session;
+pub mod cache;
+pub mod http;
+pub mod examples;
+pub mod range;
+pub mod guard;
+pub mod grid;
+
+#[cfg(all(test, feature = "fetch"))]
+pub mod stub_server;
@@ -0,0 +1,124 @@
+use std::fmt;
+
+use num_bigint::BigInt;
+
+/// The answer of a part. Two answers are equal when they print the same way,
+/// so a `U64(3)` matches an `I64(3)` or a `Text("3")` read back from a file.
+#[derive(Debug, Clone)]
+pub enum Answer {
+    I64(i64),
+    U64(u64),
+    I128(i128),
+    U128(u128),
+    Big(BigInt),
+    Text(String),
+    /// Several lines, like letters drawn on a grid.
+    Multiline(String)
+}
+
+impl Answer {
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Answer::Multiline(_))
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::I64(value) => write!(f, "{value}"),
+            Answer::U64(value) => write!(f, "{value}"),
+            Answer::I128(value) => write!(f, "{value}"),
+            Answer::U128(value) => write!(f, "{value}"),
+            Answer::Big(value) => write!(f, "{value}"),
+            Answer::Text(value) => write!(f, "{value}"),
+            Answer::Multiline(value) => write!(f, "{}", value.trim_end())
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for Answer {}
+
+impl PartialEq<i64> for Answer {
+    fn eq(&self, other: &i64) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::I64(value)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::U64(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::U64(value as u64)
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(value: i128) -> Self {
+        Answer::I128(value)
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        Answer::U128(value)
+    }
+}
+
+impl From<BigInt> for Answer {
+    fn from(value: BigInt) -> Self {
+        Answer::Big(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        if value.trim_end().contains('\n') { Answer::Multiline(value) } else { Answer::Text(value) }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::from(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigInt;
+
+    use crate::utils::answer::Answer;
+
+    #[test]
+    fn it_test_compare_across_variants() {
+        assert_eq!(Answer::from(42_u64), Answer::I64(42));
+        assert_eq!(Answer::from("42"), Answer::U128(42));
+        assert_eq!(Answer::from(BigInt::from(u128::MAX) * 2), Answer::from("680564733841876926926749214863536422910"));
+        assert_ne!(Answer::I64(-1), Answer::U64(1));
+        assert_eq!(Answer::from(7_usize), 7);
+    }
+
+    #[test]
+    fn it_test_multiline() {
+        let answer = Answer::from("#..#\n####\n");
+
+        assert!(answer.is_multiline());
+        assert!(!Answer::from("ABC").is_multiline());
+        assert_eq!(answer, Answer::Multiline("#..#\n####".to_string()));
+    }
+}
@@ -0,0 +1,219 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::utils::config::{config_path, Config};
+use crate::utils::session;
+
+/// Where the inputs were saved before the cache, for the 2025 puzzles only.
+const LEGACY_INPUT_DIR: &str = "./src/input";
+
+/// A cached input file.
+#[derive(Debug, PartialEq)]
+pub struct CachedInput {
+    pub year: i16,
+    pub day: i8,
+    pub path: PathBuf,
+    pub size: u64
+}
+
+/// The downloaded inputs, in `<root>/<user>/<year>/day<N>.txt`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputCache {
+    root: PathBuf,
+    user: String
+}
+
+/// Keeps the letters, digits, `-` and `_` of a user name so it can be a directory.
+fn user_dir_name(user: &str) -> String {
+    let name: String = user.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect();
+    if name.is_empty() { "default".to_string() } else { name }
+}
+
+/// A name for the account of a session without its token, the FNV-1a hash of the token being stable across builds.
+fn session_key(token: &str) -> String {
+    let hash = token.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x100000001b3));
+    format!("session-{hash:016x}")
+}
+
+/// `$XDG_CACHE_HOME/aoc`, or `~/.cache/aoc`.
+fn default_root() -> Option<PathBuf> {
+    let cache_dir = env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".cache")))?;
+    Some(cache_dir.join("aoc"))
+}
+
+impl InputCache {
+    pub fn new(root: &Path, user: &str) -> InputCache {
+        InputCache { root: root.to_path_buf(), user: user_dir_name(user) }
+    }
+
+    /// Uses the `cache_dir` of the user config, or the XDG cache directory. The inputs are kept per user: the
+    /// `user` of the config, else a key of the current session, else the default user when there is no session.
+    pub fn resolve() -> Result<InputCache, String> {
+        let config = match config_path() {
+            Some(path) => Config::load(&path)?,
+            None => Config::default()
+        };
+        let root = config.cache_dir.map(PathBuf::from)
+            .or_else(default_root)
+            .ok_or("No cache directory, set cache_dir in the config, XDG_CACHE_HOME or HOME".to_string())?;
+        let user = config.user
+            .or_else(|| session::resolve().ok().map(|session| session_key(&session.token)))
+            .unwrap_or_default();
+        Ok(InputCache::new(&root, &user))
+    }
+
+    fn user_dir(&self) -> PathBuf {
+        self.root.join(&self.user)
+    }
+
+    pub fn input_path(&self, year: i16, day: i8) -> PathBuf {
+        self.user_dir().join(year.to_string()).join(format!("day{day}.txt"))
+    }
+
+    /// The saved copy of the puzzle page, next to its input.
+    pub fn page_path(&self, year: i16, day: i8) -> PathBuf {
+        self.user_dir().join(year.to_string()).join(format!("day{day}.html"))
+    }
+
+    pub fn read_page(&self, year: i16, day: i8) -> Option<String> {
+        fs::read_to_string(self.page_path(year, day)).ok()
+    }
+
+    pub fn write_page(&self, year: i16, day: i8, content: &str) -> Result<(), String> {
+        let path = self.page_path(year, day);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Unable to create the cache directory {}: {e}", parent.display()))?;
+        }
+        fs::write(&path, content)
+            .map_err(|e| format!("Unable to write the puzzle page {}: {e}", path.display()))
+    }
+
+    /// Copies the input saved in `src/input` by the older versions, if there is one.
+    fn migrate_legacy(&self, year: i16, day: i8) -> Result<bool, String> {
+        let legacy_path = Path::new(LEGACY_INPUT_DIR).join(format!("input_day{day}.txt"));
+        if year != 2025 || !legacy_path.exists() {
+            return Ok(false);
+        }
+        let content = fs::read_to_string(&legacy_path)
+            .map_err(|e| format!("Unable to read the input file {}: {e}", legacy_path.display()))?;
+        self.write(year, day, &content)?;
+        Ok(true)
+    }
+
+    /// The cached input of the day, if there is one.
+    pub fn read(&self, year: i16, day: i8) -> Result<Option<String>, String> {
+        let path = self.input_path(year, day);
+        if !path.exists() && !self.migrate_legacy(year, day)? {
+            return Ok(None);
+        }
+        fs::read_to_string(&path)
+            .map(Some)
+            .map_err(|e| format!("Unable to read the input file {}: {e}", path.display()))
+    }
+
+    pub fn write(&self, year: i16, day: i8, content: &str) -> Result<PathBuf, String> {
+        let path = self.input_path(year, day);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Unable to create the cache directory {}: {e}", parent.display()))?;
+        }
+        fs::write(&path, content)
+            .map_err(|e| format!("Unable to write the input file {}: {e}", path.display()))?;
+        Ok(path)
+    }
+
+    /// Every cached input of the user, sorted by year then day.
+    pub fn list(&self) -> Result<Vec<CachedInput>, String> {
+        let user_dir = self.user_dir();
+        let year_entries = match fs::read_dir(&user_dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(e) => return Err(format!("Unable to read the cache directory {}: {e}", user_dir.display()))
+        };
+
+        let mut inputs = vec![];
+        for year_entry in year_entries.flatten() {
+            let Some(year) = year_entry.file_name().to_str().and_then(|name| name.parse::<i16>().ok()) else {
+                continue;
+            };
+            let day_entries = fs::read_dir(year_entry.path())
+                .map_err(|e| format!("Unable to read the cache directory {}: {e}", year_entry.path().display()))?;
+            for day_entry in day_entries.flatten() {
+                let file_name = day_entry.file_name();
+                let Some(day) = file_name.to_str()
+                    .and_then(|name| name.strip_prefix("day")?.strip_suffix(".txt")?.parse::<i8>().ok()) else {
+                    continue;
+                };
+                let size = day_entry.metadata().map(|metadata| metadata.len()).unwrap_or_default();
+                inputs.push(CachedInput { year, day, path: day_entry.path(), size });
+            }
+        }
+        inputs.sort_by_key(|input| (input.year, input.day));
+        Ok(inputs)
+    }
+
+    /// Removes the cached inputs of the year, only of the given days if any. Gives the removed ones.
+    pub fn clear(&self, year: i16, days: Option<&[i8]>) -> Result<Vec<CachedInput>, String> {
+        let removed: Vec<CachedInput> = self.list()?.into_iter()
+            .filter(|input| input.year == year && days.is_none_or(|days| days.contains(&input.day)))
+            .collect();
+        for input in &removed {
+            fs::remove_file(&input.path)
+                .map_err(|e| format!("Unable to remove the input file {}: {e}", input.path.display()))?;
+        }
+        Ok(removed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+
+    use crate::utils::cache::{session_key, InputCache};
+
+    #[test]
+    fn it_test_write_list_and_clear() {
+        let root = env::temp_dir().join(format!("aoc_cache_{}", std::process::id()));
+        let cache = InputCache::new(&root, "Santa Claus");
+        cache.write(2024, 3, "L68\n").expect("The input should be written");
+        cache.write(2024, 1, "L30\n").expect("The input should be written");
+        cache.write(2023, 1, "R48\n").expect("The input should be written");
+
+        cache.write_page(2024, 3, "<article></article>").expect("The page should be written");
+        let read = cache.read(2024, 3).expect("The input should be read");
+        let page = cache.read_page(2024, 3);
+        let missing = cache.read(2024, 2).expect("The missing input should not be an error");
+        let listed: Vec<(i16, i8)> = cache.list().expect("The inputs should be listed").iter()
+            .map(|input| (input.year, input.day))
+            .collect();
+        let removed = cache.clear(2024, Some(&[1, 2])).expect("The inputs should be removed");
+        let left = cache.list().expect("The inputs should be listed").len();
+        fs::remove_dir_all(&root).expect("The cache should be removed");
+
+        assert_eq!(cache.input_path(2024, 3), root.join("Santa_Claus").join("2024").join("day3.txt"));
+        assert_eq!(read, Some("L68\n".to_string()));
+        assert_eq!(page, Some("<article></article>".to_string()));
+        assert_eq!(missing, None);
+        assert_eq!(listed, vec![(2023, 1), (2024, 1), (2024, 3)]);
+        assert_eq!(removed.len(), 1);
+        assert_eq!(left, 2);
+    }
+
+    #[test]
+    fn it_test_session_key() {
+        let key = session_key("53616c7465645f5f");
+
+        assert_eq!(key, session_key("53616c7465645f5f"));
+        assert_ne!(key, session_key("53616c7465645f5e"));
+        assert!(!key.contains("53616c"));
+        assert_eq!(InputCache::new(&env::temp_dir(), &key).input_path(2025, 1).parent().and_then(|year| year.parent()), Some(env::temp_dir().join(&key).as_path()));
+    }
+}
@@ -0,0 +1,74 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+/// The user settings, read from `config.toml` in the user config directory.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Config {
+    pub session: Option<String>,
+    /// How to reach you, added to the User-Agent of the requests to the site.
+    pub contact: Option<String>,
+    /// Where to keep the downloaded inputs, instead of the XDG cache directory.
+    pub cache_dir: Option<String>,
+    /// The user of the session, to keep the inputs of each user apart. Saved by the login command.
+    pub user: Option<String>
+}
+
+/// `$AOC_CONFIG`, or `aoc/config.toml` in `$XDG_CONFIG_HOME` or `~/.config`.
+pub fn config_path() -> Option<PathBuf> {
+    if let Some(path) = env::var_os("AOC_CONFIG") {
+        return Some(PathBuf::from(path));
+    }
+    let config_dir = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+    Some(config_dir.join("aoc").join("config.toml"))
+}
+
+impl Config {
+    /// Loads the config, a missing file being an empty config.
+    pub fn load(path: &Path) -> Result<Config, String> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Config::default()),
+            Err(e) => return Err(format!("Unable to read the config {}: {e}", path.display()))
+        };
+        toml::from_str(&content)
+            .map_err(|e| format!("The config {} is not valid: {e}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let content = toml::to_string(self)
+            .map_err(|e| format!("Unable to serialize the config: {e}"))?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Unable to create the config directory {}: {e}", parent.display()))?;
+        }
+        fs::write(path, content)
+            .map_err(|e| format!("Unable to write the config {}: {e}", path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+
+    use crate::utils::config::Config;
+
+    #[test]
+    fn it_test_save_and_load() {
+        let path = env::temp_dir().join(format!("aoc_config_{}", std::process::id())).join("config.toml");
+        let config = Config { session: Some("abc123".to_string()), user: Some("Santa".to_string()), ..Config::default() };
+        config.save(&path).expect("The config should be saved");
+
+        let loaded = Config::load(&path).expect("The config should be loaded");
+        fs::remove_dir_all(path.parent().expect("The config should be in a directory")).expect("The config should be removed");
+
+        assert_eq!(loaded, config);
+        assert_eq!(Config::load(&path), Ok(Config::default()));
+    }
+}
@@ -1,8 +1,90 @@
+use std::any::Any;
+
+use crate::utils::answer::Answer;
 use crate::utils::day_error::DayError;
 
-pub trait Day {
+pub trait Day: Sync {
+    /// What `parse` turns the raw input into, shared by both parts.
+    type Input: Send + Sync + 'static;
+
     fn get_nb(&self) -> i8;
 
-    fn part1(&self, input: String) -> Result<i64, DayError<'_>>;
-    fn part2(&self, input: String) -> Result<i64, DayError<'_>>;
-}
\ No newline at end of file
+    fn parse(&self, input: &str) -> Result<Self::Input, DayError>;
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer, DayError>;
+    fn part2(&self, input: &Self::Input) -> Result<Answer, DayError>;
+
+    /// Parses then solves the part 1, handy for the tests.
+    fn solve_part1(&self, input: &str) -> Result<Answer, DayError> {
+        self.part1(&self.parse(input)?)
+    }
+
+    /// Parses then solves the part 2, handy for the tests.
+    fn solve_part2(&self, input: &str) -> Result<Answer, DayError> {
+        self.part2(&self.parse(input)?)
+    }
+}
+
+/// The output of `Solver::parse`, to give back to `Solver::part1` or `Solver::part2`.
+pub type ParsedInput = Box<dyn Any + Send + Sync>;
+
+/// Object safe version of `Day`, so all the days can live in the same registry.
+/// It is implemented for every `Day`.
+pub trait Solver: Sync {
+    fn get_nb(&self) -> i8;
+
+    fn parse(&self, input: &str) -> Result<ParsedInput, DayError>;
+
+    fn part1(&self, input: &ParsedInput) -> Result<Answer, DayError>;
+    fn part2(&self, input: &ParsedInput) -> Result<Answer, DayError>;
+
+    fn run_part(&self, part: i8, input: &ParsedInput) -> Result<Answer, DayError> {
+        if part == 1 { self.part1(input) } else { self.part2(input) }
+    }
+}
+
+fn downcast<'a, D: Day>(day: &D, input: &'a ParsedInput) -> Result<&'a D::Input, DayError> {
+    input.downcast_ref::<D::Input>()
+        .ok_or_else(|| DayError::solver(format!("The input given to day{} was parsed by another day", day.get_nb())))
+}
+
+impl<D: Day> Solver for D {
+    fn get_nb(&self) -> i8 {
+        Day::get_nb(self)
+    }
+
+    fn parse(&self, input: &str) -> Result<ParsedInput, DayError> {
+        Ok(Box::new(Day::parse(self, input)?))
+    }
+
+    fn part1(&self, input: &ParsedInput) -> Result<Answer, DayError> {
+        Day::part1(self, downcast(self, input)?)
+    }
+
+    fn part2(&self, input: &ParsedInput) -> Result<Answer, DayError> {
+        Day::part2(self, downcast(self, input)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::y2025::day1::Day1;
+    use crate::days::y2025::day3::Day3;
+    use crate::utils::day::Solver;
+
+    #[test]
+    fn it_test_parse_once_for_both_parts() {
+        let parsed = Solver::parse(&Day1, "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82").expect("There should be a parsed input");
+
+        assert_eq!(Solver::part1(&Day1, &parsed).expect("There should be a result"), 3);
+        assert_eq!(Solver::part2(&Day1, &parsed).expect("There should be a result"), 6);
+    }
+
+    #[test]
+    fn it_test_input_of_another_day() {
+        let parsed = Solver::parse(&Day1, "L68").expect("There should be a parsed input");
+        let error = Solver::part1(&Day3, &parsed).expect_err("There should be an error");
+
+        assert_eq!(error.to_string(), "Solver failure: The input given to day3 was parsed by another day");
+    }
+}
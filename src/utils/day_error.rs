@@ -1,4 +1,125 @@
+use std::error::Error;
+use std::fmt;
+use std::time::Duration;
+
+type Source = Box<dyn Error + Send + Sync + 'static>;
+
 #[derive(Debug)]
-pub struct DayError<'a> {
-    pub message: &'a str
-}
\ No newline at end of file
+pub enum DayError {
+    /// The input doesn't look like what the day expects. Lines and columns start at 1.
+    Parse {
+        line: usize,
+        column: Option<usize>,
+        text: String,
+        message: String,
+        source: Option<Source>
+    },
+    /// The part has no working solution yet.
+    Unsolved {
+        part: i8
+    },
+    /// The input was fine, but the solver couldn't find an answer.
+    Solver {
+        message: String,
+        source: Option<Source>
+    },
+    /// The solver panicked, caught by the runner.
+    Panicked {
+        message: String
+    },
+    /// The solver was given up after running longer than the limit.
+    TimedOut {
+        limit: Duration
+    }
+}
+
+impl DayError {
+    pub fn parse(line: usize, text: &str, message: impl Into<String>) -> DayError {
+        DayError::Parse { line, column: None, text: text.to_string(), message: message.into(), source: None }
+    }
+
+    pub fn solver(message: impl Into<String>) -> DayError {
+        DayError::Solver { message: message.into(), source: None }
+    }
+
+    /// Sets the column of a parse error, does nothing on the other variants.
+    pub fn at_column(mut self, new_column: usize) -> DayError {
+        if let DayError::Parse { column, .. } = &mut self {
+            *column = Some(new_column);
+        }
+        self
+    }
+
+    /// Moves a parse error `offset` lines further, for inputs parsed by sections.
+    pub fn shift_lines(mut self, offset: usize) -> DayError {
+        if let DayError::Parse { line, .. } = &mut self {
+            *line += offset;
+        }
+        self
+    }
+
+    /// Moves the column of a parse error `offset` columns further, for lines parsed by pieces.
+    pub fn shift_columns(mut self, offset: usize) -> DayError {
+        if let DayError::Parse { column: Some(column), .. } = &mut self {
+            *column += offset;
+        }
+        self
+    }
+
+    pub fn with_source(mut self, error: impl Error + Send + Sync + 'static) -> DayError {
+        match &mut self {
+            DayError::Parse { source, .. } | DayError::Solver { source, .. } => *source = Some(Box::new(error)),
+            DayError::Unsolved { .. } | DayError::Panicked { .. } | DayError::TimedOut { .. } => {}
+        }
+        self
+    }
+}
+
+impl fmt::Display for DayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DayError::Parse { line, column: Some(column), text, message, .. } =>
+                write!(f, "Parse error at line {line}, column {column}: {message} (in `{text}`)"),
+            DayError::Parse { line, column: None, text, message, .. } =>
+                write!(f, "Parse error at line {line}: {message} (in `{text}`)"),
+            DayError::Unsolved { part } => write!(f, "The part {part} is not solved yet"),
+            DayError::Solver { message, .. } => write!(f, "Solver failure: {message}"),
+            DayError::Panicked { message } => write!(f, "The solver panicked: {message}"),
+            DayError::TimedOut { limit } => write!(f, "The solver was stopped after {limit:.2?}")
+        }
+    }
+}
+
+impl Error for DayError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            DayError::Parse { source, .. } | DayError::Solver { source, .. } =>
+                source.as_ref().map(|error| error.as_ref() as &(dyn Error + 'static)),
+            DayError::Unsolved { .. } | DayError::Panicked { .. } | DayError::TimedOut { .. } => None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use crate::utils::day_error::DayError;
+
+    #[test]
+    fn it_test_display_parse() {
+        let error = DayError::parse(3, "L1x", "Not a number").at_column(2).shift_lines(2);
+
+        assert_eq!(error.to_string(), "Parse error at line 5, column 2: Not a number (in `L1x`)");
+    }
+
+    #[test]
+    fn it_test_source_chain() {
+        let source = "1x".parse::<i64>().expect_err("This should not be a number");
+        let error = DayError::parse(1, "1x", "Not a number").with_source(source);
+
+        let source_message = error.source().map(|source| source.to_string());
+        assert_eq!(source_message, Some("invalid digit found in string".to_string()));
+        assert!(DayError::Unsolved { part: 2 }.source().is_none());
+    }
+}
@@ -0,0 +1,208 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// An example of a puzzle, with the answers the description gives for it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Example {
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>
+}
+
+/// The expected answers of an example file, as saved in `examples/<YEAR>/day<N>.json`.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct ExampleEntry {
+    file: String,
+    part1: Option<String>,
+    part2: Option<String>
+}
+
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+struct ExampleSet {
+    examples: Vec<ExampleEntry>
+}
+
+/// `./examples/<YEAR>`, where the examples of the year are saved.
+pub fn examples_dir(year: i16) -> PathBuf {
+    Path::new("./examples").join(year.to_string())
+}
+
+/// Decodes the few entities the puzzle pages use.
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// The text of an HTML fragment, without its tags like the `<em>` highlighting parts of an example.
+fn html_text(html: &str) -> String {
+    let tags = Regex::new(r"<[^>]*>").expect("Should not have issue with regex");
+    decode_entities(&tags.replace_all(html, ""))
+}
+
+/// The first `<pre><code>` block of an article, usually the one after "For example".
+fn first_example(article: &str) -> Option<String> {
+    let re = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").expect("Should not have issue with regex");
+    let block = re.captures(article)?.get(1)?.as_str();
+    Some(html_text(block))
+}
+
+/// The last emphasized code of an article, which is the answer of the example by convention.
+fn last_answer(article: &str) -> Option<String> {
+    let re = Regex::new(r"(?s)<code><em>(.*?)</em></code>|<em><code>(.*?)</code></em>").expect("Should not have issue with regex");
+    let captures = re.captures_iter(article).last()?;
+    let answer = captures.get(1).or_else(|| captures.get(2))?.as_str();
+    Some(html_text(answer).trim().to_string())
+}
+
+/// Extracts the examples of a puzzle page and their expected answers. The part 2 usually reuses the
+/// example of the part 1; it only gets its own when its description starts with a new one.
+pub fn extract_examples(html: &str) -> Vec<Example> {
+    let re = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").expect("Should not have issue with regex");
+    let articles: Vec<&str> = re.captures_iter(html)
+        .filter_map(|captures| Some(captures.get(1)?.as_str()))
+        .collect();
+
+    let mut examples: Vec<Example> = vec![];
+    if let Some(part1) = articles.first() {
+        if let Some(input) = first_example(part1) {
+            examples.push(Example { input, part1: last_answer(part1), part2: None });
+        }
+    }
+    if let Some(part2) = articles.get(1) {
+        let answer = last_answer(part2);
+        match first_example(part2) {
+            Some(input) if examples.iter().all(|example| example.input != input) => {
+                examples.push(Example { input, part1: None, part2: answer });
+            },
+            _ => {
+                if let Some(example) = examples.first_mut() {
+                    example.part2 = answer;
+                }
+            }
+        }
+    }
+    examples
+}
+
+/// Whether the page shows the part 2, which only happens once the part 1 is solved.
+pub fn has_part2(html: &str) -> bool {
+    html.matches("<article").count() >= 2
+}
+
+/// The title of the puzzle, from the `--- Day 4: Printing Department ---` heading of the page.
+pub fn puzzle_title(html: &str) -> Option<String> {
+    let re = Regex::new(r"<h2>--- Day \d+: (.*?) ---</h2>").expect("Should not have issue with regex");
+    Some(html_text(re.captures(html)?.get(1)?.as_str()))
+}
+
+/// Saves the examples as `day<N>_<M>.txt` files and their expected answers in `day<N>.json`.
+pub fn save_examples(dir: &Path, day: i8, examples: &[Example]) -> Result<Vec<PathBuf>, String> {
+    fs::create_dir_all(dir)
+        .map_err(|e| format!("Unable to create the examples directory {}: {e}", dir.display()))?;
+
+    let mut set = ExampleSet::default();
+    let mut paths = vec![];
+    for (idx, example) in examples.iter().enumerate() {
+        let file = format!("day{day}_{}.txt", idx + 1);
+        let path = dir.join(&file);
+        fs::write(&path, &example.input)
+            .map_err(|e| format!("Unable to write the example {}: {e}", path.display()))?;
+        set.examples.push(ExampleEntry { file, part1: example.part1.clone(), part2: example.part2.clone() });
+        paths.push(path);
+    }
+
+    let path = dir.join(format!("day{day}.json"));
+    let content = serde_json::to_string_pretty(&set)
+        .map_err(|e| format!("Unable to serialize the examples: {e}"))?;
+    fs::write(&path, content + "\n")
+        .map_err(|e| format!("Unable to write the examples {}: {e}", path.display()))?;
+    paths.push(path);
+    Ok(paths)
+}
+
+/// Loads the examples saved by `save_examples` with their file names, none when the day has no `day<N>.json`.
+pub fn load_examples(dir: &Path, day: i8) -> Result<Vec<(String, Example)>, String> {
+    let path = dir.join(format!("day{day}.json"));
+    let content = match fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(format!("Unable to read the examples {}: {e}", path.display()))
+    };
+    let set: ExampleSet = serde_json::from_str(&content)
+        .map_err(|e| format!("The examples {} are not valid: {e}", path.display()))?;
+
+    set.examples.into_iter()
+        .map(|entry| {
+            let input_path = dir.join(&entry.file);
+            let input = fs::read_to_string(&input_path)
+                .map_err(|e| format!("Unable to read the example {}: {e}", input_path.display()))?;
+            Ok((entry.file, Example { input, part1: entry.part1, part2: entry.part2 }))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+
+    use crate::utils::examples::{extract_examples, has_part2, load_examples, puzzle_title, save_examples, Example};
+
+    const BOTH_PARTS: &str = include_str!("../../tests/fixtures/puzzle_both_parts.html");
+    const NEW_EXAMPLE: &str = include_str!("../../tests/fixtures/puzzle_new_example.html");
+
+    #[test]
+    fn it_test_extract_both_parts() {
+        let examples = extract_examples(BOTH_PARTS);
+
+        assert!(has_part2(BOTH_PARTS));
+        assert_eq!(puzzle_title(BOTH_PARTS), Some("Fixture Dial".to_string()));
+        assert_eq!(examples, vec![Example {
+            input: "L68\nL30\nR48\n".to_string(),
+            part1: Some("3".to_string()),
+            part2: Some("6".to_string())
+        }]);
+    }
+
+    #[test]
+    fn it_test_extract_new_example_for_part2() {
+        let examples = extract_examples(NEW_EXAMPLE);
+
+        let part1_only = &NEW_EXAMPLE[..NEW_EXAMPLE.find("<p>Your puzzle answer").expect("The fixture should have an answer")];
+        assert!(!has_part2(part1_only));
+        assert_eq!(examples.len(), 2);
+        assert_eq!(examples[0].input, "a->b\nb->out\n");
+        assert_eq!(examples[0].part1, Some("1".to_string()));
+        assert_eq!(examples[0].part2, None);
+        assert_eq!(examples[1].input, "svr -> <a>\n");
+        assert_eq!(examples[1].part2, Some("2".to_string()));
+    }
+
+    #[test]
+    fn it_test_extract_without_example() {
+        assert!(extract_examples("<html><article><p>No example here</p></article></html>").is_empty());
+    }
+
+    #[test]
+    fn it_test_save_and_load() {
+        let dir = env::temp_dir().join(format!("aoc_examples_{}", std::process::id()));
+        let examples = extract_examples(NEW_EXAMPLE);
+        let paths = save_examples(&dir, 11, &examples).expect("The examples should be saved");
+
+        let loaded = load_examples(&dir, 11).expect("The examples should be loaded");
+        let missing = load_examples(&dir, 12).expect("A day without examples should not be an error");
+        fs::remove_dir_all(&dir).expect("The examples should be removed");
+
+        assert_eq!(paths.len(), 3);
+        assert_eq!(loaded[1].0, "day11_2.txt");
+        assert_eq!(loaded.into_iter().map(|(_, example)| example).collect::<Vec<Example>>(), examples);
+        assert!(missing.is_empty());
+    }
+}
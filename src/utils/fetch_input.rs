@@ -1,39 +1,288 @@
 use std::fs;
+use std::thread;
+use std::time::Duration;
 
-use reqwest::{header::{self, HeaderMap, HeaderValue}};
+use crate::utils::cache::InputCache;
+use crate::utils::config::{config_path, Config};
+use crate::utils::http::{self, HttpError, USER_AGENT};
+use crate::utils::session::{self, Session};
+use crate::utils::submit::DEFAULT_BASE_URL;
 
-pub async fn get_input_data(day: i8) -> Option<String> {
-    let file_path = format!("./src/input/input_day{day}.txt");
-    let from_file = fs::exists(&file_path).expect("File unreadable");
-    if from_file {
-        println!("Reading input file...");
-        fs::read_to_string(file_path).ok()
-    } else {
-        println!("Getting input from api...");
-        let data = get_input_data_from_api(day).await;
+pub fn get_example_data(year: i16, day: i8) -> Option<String> {
+    let file_path = format!("./examples/{year}/day{day}_1.txt");
+    fs::read_to_string(file_path).ok()
+}
+
+/// Why `body` can't be a puzzle input, if it looks like an empty answer or an HTML page.
+pub fn check_input_body(body: &str) -> Result<(), String> {
+    let start = body.trim_start();
+    if start.is_empty() {
+        return Err("The input is empty".to_string());
+    }
+    let lowercase = start.to_lowercase();
+    if lowercase.starts_with("<!doctype") || lowercase.starts_with("<html") {
+        return Err("The input is an HTML page".to_string());
+    }
+    if start.starts_with("Please don't repeatedly request") || start.starts_with("Puzzle inputs differ by user") {
+        let first_line = start.lines().next().unwrap_or_default();
+        return Err(format!("The input is an error message: {first_line}"));
+    }
+    Ok(())
+}
+
+/// The result of one attempt, telling whether trying again may help.
+enum Attempt {
+    Done(String),
+    Retry(String),
+    Fail(String)
+}
+
+/// Downloads the inputs, retrying when the site is busy or unreachable.
+pub struct Fetcher {
+    base_url: String,
+    session: Session,
+    user_agent: String,
+    retries: u32,
+    backoff: Duration
+}
+
+impl Fetcher {
+    pub fn new(base_url: &str, session: Session) -> Fetcher {
+        Fetcher {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            user_agent: USER_AGENT.to_string(),
+            retries: 3,
+            backoff: Duration::from_secs(1)
+        }
+    }
+
+    /// Adds a way to reach the owner of the tool to the User-Agent, like an email.
+    pub fn with_contact(mut self, contact: &str) -> Fetcher {
+        self.user_agent = format!("{USER_AGENT} by {contact}");
+        self
+    }
+
+    /// Tries `retries` more times after a failure that may be temporary, waiting `backoff` then twice longer each time.
+    pub fn with_retries(mut self, retries: u32, backoff: Duration) -> Fetcher {
+        self.retries = retries;
+        self.backoff = backoff;
+        self
+    }
+
+    fn attempt(&self, url: &str, check_body: fn(&str) -> Result<(), String>) -> Attempt {
+        let response = match http::get(url, &self.session, &self.user_agent) {
+            Ok(response) => response,
+            Err(error @ (HttpError::Disabled | HttpError::InvalidSession(_))) => return Attempt::Fail(error.to_string()),
+            Err(HttpError::Transport(message)) => return Attempt::Retry(message)
+        };
 
-        if let Some(content) = &data {
-            println!("Writing input into file...");
-            let res = fs::write(file_path, content);
-            if let Err(e) = res {
-                println!("An error occured while writing the file: {e}");
+        let status = response.status;
+        match status {
+            400 | 401 | 403 => {
+                return Attempt::Fail(format!("The session from {} was refused ({status}), it may have expired. Run the login command", self.session.source));
+            },
+            404 => return Attempt::Fail(format!("Nothing at {url}, the puzzle may not be unlocked yet")),
+            429 => return Attempt::Retry(format!("Too many requests to {url}")),
+            500..=599 => return Attempt::Retry(format!("{url} answered {status}")),
+            _ if !response.is_success() => return Attempt::Fail(format!("{url} answered {status}")),
+            _ => {}
+        }
+
+        match check_body(&response.body) {
+            Ok(()) => Attempt::Done(response.body),
+            Err(message) => Attempt::Fail(format!("{message}, from {url}"))
+        }
+    }
+
+    /// Gets `url` until it works, gives up or runs out of tries. `check_body` refuses the bodies that are error pages.
+    fn fetch(&self, url: &str, check_body: fn(&str) -> Result<(), String>) -> Result<String, String> {
+        let mut backoff = self.backoff;
+        let mut tries_left = self.retries;
+        loop {
+            match self.attempt(url, check_body) {
+                Attempt::Done(body) => return Ok(body),
+                Attempt::Fail(message) => return Err(message),
+                Attempt::Retry(message) if tries_left == 0 => {
+                    return Err(format!("{message}, after {} tries", self.retries + 1));
+                },
+                Attempt::Retry(message) => {
+                    eprintln!("{message}, trying again in {backoff:?}");
+                    thread::sleep(backoff);
+                    backoff *= 2;
+                    tries_left -= 1;
+                }
             }
         }
-        data
-    }
-}
-
-pub async fn get_input_data_from_api(day: i8) -> Option<String> {
-    let current_cookie = "session=53616c7465645f5f69451cdf5cbb3ea9d9a86e7468aee5f4bcfae0bf2dea81219a0cd4d54f0b7bd7742d72b2284d1823258f10da11a81a8708a1f35710a56658";
-    let mut headers = HeaderMap::new();
-    headers.insert(header::COOKIE, HeaderValue::from_str(current_cookie).unwrap());
-    let url = format!("https://adventofcode.com/2025/day/{day}/input");
-    let response = reqwest::Client::new()
-        .get(url)
-        .headers(headers)
-        .send()
-        .await.ok()?
-        .text()
-        .await.ok()?;
-    Some(response)
-}
\ No newline at end of file
+    }
+
+    pub fn fetch_input(&self, year: i16, day: i8) -> Result<String, String> {
+        self.fetch(&format!("{}/{year}/day/{day}/input", self.base_url), check_input_body)
+    }
+
+    /// The HTML page of the puzzle, with its part 2 once the part 1 is solved.
+    pub fn fetch_page(&self, year: i16, day: i8) -> Result<String, String> {
+        self.fetch(&format!("{}/{year}/day/{day}", self.base_url), check_page_body)
+    }
+}
+
+/// Why `body` can't be a puzzle page, if it has no puzzle description.
+pub fn check_page_body(body: &str) -> Result<(), String> {
+    if body.contains("<article") { Ok(()) } else { Err("The page has no puzzle description".to_string()) }
+}
+
+/// A fetcher with the session and the contact of the user.
+pub fn default_fetcher() -> Result<Fetcher, String> {
+    let mut fetcher = Fetcher::new(DEFAULT_BASE_URL, session::resolve()?);
+    let config = match config_path() {
+        Some(path) => Config::load(&path)?,
+        None => Config::default()
+    };
+    if let Some(contact) = &config.contact {
+        fetcher = fetcher.with_contact(contact);
+    }
+    Ok(fetcher)
+}
+
+/// The input of the day, from the cache or downloaded then cached.
+pub fn get_input_data(year: i16, day: i8, quiet: bool) -> Result<String, String> {
+    let cache = InputCache::resolve()?;
+    if let Some(content) = cache.read(year, day)? {
+        if !quiet {
+            println!("Reading input file...");
+        }
+        // An older version could save the error pages of the site
+        match check_input_body(&content) {
+            Ok(()) => return Ok(content),
+            Err(message) => eprintln!("Ignoring the input file {}: {message}", cache.input_path(year, day).display())
+        }
+    }
+
+    download_input(&cache, year, day, quiet)
+}
+
+/// Downloads the input of the day, replacing the cached one if any.
+pub fn download_input(cache: &InputCache, year: i16, day: i8, quiet: bool) -> Result<String, String> {
+    if !quiet {
+        println!("Getting input from api...");
+    }
+    let content = get_input_data_from_api(year, day)?;
+
+    if !quiet {
+        println!("Writing input into file...");
+    }
+    if let Err(message) = cache.write(year, day, &content) {
+        eprintln!("An error occurred while writing the input file: {message}");
+    }
+    Ok(content)
+}
+
+pub fn get_input_data_from_api(year: i16, day: i8) -> Result<String, String> {
+    if !cfg!(feature = "fetch") {
+        return Err(format!("The input of {year} day{day} is not cached. {}", HttpError::Disabled));
+    }
+    default_fetcher()?.fetch_input(year, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::utils::fetch_input::{check_input_body, Fetcher};
+    use crate::utils::session::{Session, SessionSource};
+    #[cfg(feature = "fetch")]
+    use crate::utils::stub_server::{serve, serve_once};
+
+    fn fetcher(base_url: &str) -> Fetcher {
+        let session = Session { token: "abc123".to_string(), source: SessionSource::Env };
+        Fetcher::new(base_url, session).with_retries(2, Duration::from_millis(1))
+    }
+
+    #[test]
+    fn it_test_check_input_body() {
+        assert!(check_input_body("L68\nL30\n").is_ok());
+        assert!(check_input_body("  \n").is_err());
+        assert!(check_input_body("<!DOCTYPE html>\n<html></html>").is_err());
+        assert!(check_input_body("Puzzle inputs differ by user.  Please log in to get your puzzle input.\n").is_err());
+    }
+
+    #[cfg(feature = "fetch")]
+    #[test]
+    fn it_test_fetch_input() {
+        let (base_url, server) = serve_once("200 OK", "L68\nL30\n".to_string());
+        let input = fetcher(&base_url).with_contact("santa@north.pole").fetch_input(2025, 1);
+        let requests = server.join().expect("The stub server should not panic");
+
+        assert_eq!(input, Ok("L68\nL30\n".to_string()));
+        assert!(requests[0].starts_with("GET /2025/day/1/input HTTP/1.1"));
+        assert!(requests[0].contains("cookie: session=abc123"));
+        assert!(requests[0].contains("user-agent: aoc_rust/"));
+        assert!(requests[0].contains("by santa@north.pole"));
+    }
+
+    #[cfg(feature = "fetch")]
+    #[test]
+    fn it_test_fetch_input_retries() {
+        let (base_url, server) = serve(vec![
+            ("503 Service Unavailable", "Busy".to_string()),
+            ("429 Too Many Requests", "Slow down".to_string()),
+            ("200 OK", "L68\n".to_string())
+        ]);
+        let input = fetcher(&base_url).fetch_input(2025, 1);
+        server.join().expect("The stub server should not panic");
+
+        assert_eq!(input, Ok("L68\n".to_string()));
+    }
+
+    #[cfg(feature = "fetch")]
+    #[test]
+    fn it_test_fetch_input_gives_up() {
+        let (base_url, server) = serve(vec![("500 Internal Server Error", "Oops".to_string()); 3]);
+        let error = fetcher(&base_url).fetch_input(2025, 1).expect_err("There should be an error");
+        server.join().expect("The stub server should not panic");
+
+        assert!(error.ends_with("after 3 tries"));
+    }
+
+    #[cfg(feature = "fetch")]
+    #[test]
+    fn it_test_fetch_input_errors() {
+        let (base_url, server) = serve(vec![
+            ("400 Bad Request", "Puzzle inputs differ by user.  Please log in to get your puzzle input.".to_string()),
+            ("404 Not Found", "Please don't repeatedly request this endpoint before it unlocks!".to_string()),
+            ("200 OK", "<!DOCTYPE html><html><body>Log in</body></html>".to_string())
+        ]);
+        let fetcher = fetcher(&base_url);
+        let expired = fetcher.fetch_input(2025, 1).expect_err("There should be an error");
+        let locked = fetcher.fetch_input(2025, 25).expect_err("There should be an error");
+        let html = fetcher.fetch_input(2025, 2).expect_err("There should be an error");
+        server.join().expect("The stub server should not panic");
+
+        assert!(expired.contains("may have expired"));
+        assert!(locked.contains("not be unlocked yet"));
+        assert!(html.starts_with("The input is an HTML page"));
+    }
+
+    #[cfg(feature = "fetch")]
+    #[test]
+    fn it_test_fetch_input_invalid_session() {
+        let (base_url, server) = serve(vec![]);
+        let session = Session { token: "abc\n123".to_string(), source: SessionSource::Env };
+        let error = Fetcher::new(&base_url, session).with_retries(2, Duration::from_millis(1))
+            .fetch_input(2025, 1)
+            .expect_err("There should be an error");
+        let requests = server.join().expect("The stub server should not panic");
+
+        assert!(requests.is_empty());
+        assert!(error.contains("is not a valid cookie"));
+        assert!(!error.ends_with("tries"), "The session should be tried once, not retried: {error}");
+    }
+
+    #[cfg(not(feature = "fetch"))]
+    #[test]
+    fn it_test_fetch_input_without_fetch() {
+        let error = fetcher("http://127.0.0.1:1").fetch_input(2025, 1).expect_err("There should be an error");
+
+        assert!(error.contains("fetch feature"));
+    }
+}
@@ -0,0 +1,242 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::utils::day_error::DayError;
+
+/// A position in a grid, as `(row, column)`.
+pub type Position = (usize, usize);
+
+const DIRECTIONS_4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+const DIRECTIONS_8: [(isize, isize); 8] = [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)];
+
+/// A rectangle of cells, stored row after row, like the character maps of the inputs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize
+}
+
+impl<T> Grid<T> {
+    /// Parses a character map, one row per line, the blank lines being skipped. The errors are
+    /// at the line and column of the input.
+    pub fn parse(text: &str) -> Result<Grid<T>, DayError>
+    where
+        T: TryFrom<char>,
+        T::Error: fmt::Display
+    {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+        for (row_idx, row) in text.lines().enumerate().filter(|(_, row)| !row.trim().is_empty()) {
+            for (col_idx, c) in row.chars().enumerate() {
+                let cell = T::try_from(c)
+                    .map_err(|e| DayError::parse(row_idx + 1, row, format!("This character is not authorized: {e}")).at_column(col_idx + 1))?;
+                cells.push(cell);
+            }
+            let row_width = row.chars().count();
+            if *width.get_or_insert(row_width) != row_width {
+                return Err(DayError::parse(row_idx + 1, row, "All the rows should have the same width"));
+            }
+            height += 1;
+        }
+        Ok(Grid { cells, width: width.unwrap_or(0), height })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (row, column): Position) -> Option<&T> {
+        (row < self.height && column < self.width).then(|| &self.cells[row * self.width + column])
+    }
+
+    pub fn get_mut(&mut self, (row, column): Position) -> Option<&mut T> {
+        (row < self.height && column < self.width).then(|| &mut self.cells[row * self.width + column])
+    }
+
+    /// All the positions, row after row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> + '_ {
+        (0..self.height).flat_map(move |row| (0..self.width).map(move |column| (row, column)))
+    }
+
+    /// All the cells with their position, row after row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    fn neighbours<'a>(&'a self, (row, column): Position, directions: &'a [(isize, isize)]) -> impl Iterator<Item = Position> + 'a {
+        directions.iter()
+            .filter_map(move |(d_row, d_column)| Some((row.checked_add_signed(*d_row)?, column.checked_add_signed(*d_column)?)))
+            .filter(|(row, column)| *row < self.height && *column < self.width)
+    }
+
+    /// The positions above, on the left, on the right and below, when they are in the grid.
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbours(position, &DIRECTIONS_4)
+    }
+
+    /// The positions around, diagonals included, when they are in the grid.
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbours(position, &DIRECTIONS_8)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(column).step_by(self.width.max(1)).take(self.height)
+    }
+
+    /// The first position of `value`, row after row.
+    pub fn find(&self, value: &T) -> Option<Position>
+    where
+        T: PartialEq
+    {
+        self.iter().find(|(_, cell)| *cell == value).map(|(position, _)| position)
+    }
+
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Position> + 'a
+    where
+        T: PartialEq
+    {
+        self.iter().filter(move |(_, cell)| *cell == value).map(|(position, _)| position)
+    }
+
+    /// The grid built from its positions in a new grid of `width` by `height`.
+    fn remap(&self, width: usize, height: usize, source: impl Fn(Position) -> Position) -> Grid<T>
+    where
+        T: Clone
+    {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |column| (row, column)))
+            .map(|position| self[source(position)].clone())
+            .collect();
+        Grid { cells, width, height }
+    }
+
+    /// The rows become the columns.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone
+    {
+        self.remap(self.height, self.width, |(row, column)| (column, row))
+    }
+
+    pub fn rotate_clockwise(&self) -> Grid<T>
+    where
+        T: Clone
+    {
+        self.remap(self.height, self.width, |(row, column)| (self.height - 1 - column, row))
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Grid<T>
+    where
+        T: Clone
+    {
+        self.remap(self.height, self.width, |(row, column)| (column, self.width - 1 - row))
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, column): Position) -> &T {
+        assert!(column < self.width, "The column {column} is out of a grid of width {}", self.width);
+        &self.cells[row * self.width + column]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, (row, column): Position) -> &mut T {
+        assert!(column < self.width, "The column {column} is out of a grid of width {}", self.width);
+        &mut self.cells[row * self.width + column]
+    }
+}
+
+/// The cells row after row, without a newline after the last row.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (row_idx, row) in self.rows().enumerate() {
+            if row_idx > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::grid::Grid;
+
+    const TEXT: &str = "abc\ndef";
+
+    #[derive(Debug)]
+    enum Tile {
+        Wall,
+        Floor
+    }
+
+    impl TryFrom<char> for Tile {
+        type Error = char;
+
+        fn try_from(value: char) -> Result<Self, Self::Error> {
+            match value {
+                '#' => Ok(Tile::Wall),
+                '.' => Ok(Tile::Floor),
+                e => Err(e)
+            }
+        }
+    }
+
+    #[test]
+    fn it_test_parse() {
+        let grid: Grid<char> = Grid::parse(&format!("\n{TEXT}\n\n")).expect("There should be a grid");
+        let tiles: Grid<Tile> = Grid::parse("#.\n.#").expect("There should be a grid");
+        let error = Grid::<Tile>::parse("#.\n\n#x").expect_err("There should be an error");
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 2)], 'f');
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.to_string(), TEXT);
+        assert_eq!(error.to_string(), "Parse error at line 3, column 2: This character is not authorized: x (in `#x`)");
+        assert!(matches!(tiles[(1, 1)], Tile::Wall) && matches!(tiles[(1, 0)], Tile::Floor));
+        assert!(Grid::<char>::parse("abc\nde").is_err());
+    }
+
+    #[test]
+    fn it_test_neighbours() {
+        let grid: Grid<char> = Grid::parse(TEXT).expect("There should be a grid");
+
+        assert_eq!(grid.neighbours4((0, 0)).collect::<Vec<_>>(), vec![(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbours8((0, 2)).collect::<Vec<_>>(), vec![(0, 1), (1, 1), (1, 2)]);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn it_test_views() {
+        let grid: Grid<char> = Grid::parse(TEXT).expect("There should be a grid");
+
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.find(&'e'), Some((1, 1)));
+        assert_eq!(grid.find(&'z'), None);
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+    }
+}
@@ -0,0 +1,106 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use crate::utils::answer::Answer;
+use crate::utils::day::{ParsedInput, Solver};
+use crate::utils::day_error::DayError;
+
+/// The stack of the threads running a part with a timeout, as large as the one of the main thread
+/// so the recursive solvers behave the same.
+const PART_STACK_SIZE: usize = 8 * 1024 * 1024;
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload.downcast_ref::<&str>().map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or("no message".to_string())
+}
+
+/// Runs `action`, turning a panic into a `DayError::Panicked`.
+fn catch_panic<T>(action: impl FnOnce() -> Result<T, DayError>) -> Result<T, DayError> {
+    panic::catch_unwind(AssertUnwindSafe(action))
+        .unwrap_or_else(|payload| Err(DayError::Panicked { message: panic_message(payload.as_ref()) }))
+}
+
+/// Parses the input of a day, a panic being an error.
+pub fn parse_guarded(day: &dyn Solver, input: &str) -> Result<Arc<ParsedInput>, DayError> {
+    catch_panic(|| day.parse(input)).map(Arc::new)
+}
+
+/// Runs a part of a day, a panic being an error. With a timeout, the part runs on its own thread and is
+/// given up once the time is over. The thread can't be stopped, it keeps running until the end of the program.
+pub fn run_part_guarded(day: &'static dyn Solver, part: i8, parsed: &Arc<ParsedInput>, timeout: Option<Duration>) -> Result<Answer, DayError> {
+    let Some(timeout) = timeout else {
+        return catch_panic(|| day.run_part(part, parsed));
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    let parsed = Arc::clone(parsed);
+    thread::Builder::new()
+        .name(format!("day{}-part{part}", day.get_nb()))
+        .stack_size(PART_STACK_SIZE)
+        .spawn(move || {
+            // The receiver is gone when the part timed out, nobody waits for the answer anymore
+            let _ = sender.send(catch_panic(|| day.run_part(part, &parsed)));
+        })
+        .map_err(|e| DayError::solver(format!("Unable to start the thread of the part {part}")).with_source(e))?;
+
+    match receiver.recv_timeout(timeout) {
+        Ok(result) => result,
+        Err(RecvTimeoutError::Timeout) => Err(DayError::TimedOut { limit: timeout }),
+        Err(RecvTimeoutError::Disconnected) => Err(DayError::Panicked { message: format!("The thread of the part {part} stopped without an answer") })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::utils::answer::Answer;
+    use crate::utils::day::Day;
+    use crate::utils::day_error::DayError;
+    use crate::utils::guard::{parse_guarded, run_part_guarded};
+
+    /// Part 1 panics, part 2 never ends, and the parsing panics on an empty input.
+    struct Faulty;
+
+    impl Day for Faulty {
+        type Input = usize;
+
+        fn get_nb(&self) -> i8 {
+            26
+        }
+
+        fn parse(&self, input: &str) -> Result<Self::Input, DayError> {
+            Ok(input.lines().next().expect("Should have a line").len())
+        }
+
+        fn part1(&self, input: &Self::Input) -> Result<Answer, DayError> {
+            let values: Vec<usize> = vec![];
+            Ok(values[*input].into())
+        }
+
+        fn part2(&self, _input: &Self::Input) -> Result<Answer, DayError> {
+            loop {
+                std::thread::sleep(Duration::from_millis(10));
+            }
+        }
+    }
+
+    #[test]
+    fn it_test_guards() {
+        let parse_error = parse_guarded(&Faulty, "").expect_err("There should be an error");
+        let parsed = parse_guarded(&Faulty, "abc").expect("There should be a parsed input");
+        let panicked = run_part_guarded(&Faulty, 1, &parsed, None).expect_err("There should be an error");
+        let panicked_on_thread = run_part_guarded(&Faulty, 1, &parsed, Some(Duration::from_secs(5))).expect_err("There should be an error");
+        let timed_out = run_part_guarded(&Faulty, 2, &parsed, Some(Duration::from_millis(50))).expect_err("There should be an error");
+
+        assert_eq!(parse_error.to_string(), "The solver panicked: Should have a line");
+        assert!(panicked.to_string().starts_with("The solver panicked: index out of bounds"));
+        assert!(matches!(panicked_on_thread, DayError::Panicked { .. }));
+        assert_eq!(timed_out.to_string(), "The solver was stopped after 50.00ms");
+    }
+}
@@ -0,0 +1,92 @@
+use std::fmt;
+
+use crate::utils::session::Session;
+
+/// Identifies the tool to the site, as its owner asks for automated requests.
+pub const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"), " (Advent of Code runner)");
+
+#[derive(Debug, PartialEq)]
+pub enum HttpError {
+    /// Built without the `fetch` feature, nothing can be downloaded.
+    Disabled,
+    /// The session can't be sent as a cookie, trying again won't help.
+    InvalidSession(String),
+    /// The request didn't get any response, it may work later.
+    Transport(String)
+}
+
+impl fmt::Display for HttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HttpError::Disabled => write!(f, "This build can't reach the site, build it with the fetch feature"),
+            HttpError::InvalidSession(message) | HttpError::Transport(message) => write!(f, "{message}")
+        }
+    }
+}
+
+pub struct Response {
+    pub status: u16,
+    pub body: String
+}
+
+impl Response {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+#[cfg(feature = "fetch")]
+mod client {
+    use reqwest::blocking::{Client, RequestBuilder};
+    use reqwest::header::{self, HeaderValue};
+
+    use crate::utils::http::{HttpError, Response};
+    use crate::utils::session::Session;
+
+    fn send(url: &str, request: RequestBuilder, session: &Session, user_agent: &str) -> Result<Response, HttpError> {
+        let cookie = HeaderValue::from_str(&format!("session={}", session.token))
+            .map_err(|e| HttpError::InvalidSession(format!("The session from {} is not a valid cookie: {e}", session.source)))?;
+        let response = request
+            .header(header::COOKIE, cookie)
+            .header(header::USER_AGENT, user_agent)
+            .send()
+            .map_err(|e| HttpError::Transport(format!("Unable to reach {url}: {e}")))?;
+
+        let status = response.status().as_u16();
+        let body = response.text()
+            .map_err(|e| HttpError::Transport(format!("Unable to read the response of {url}: {e}")))?;
+        Ok(Response { status, body })
+    }
+
+    pub fn get(url: &str, session: &Session, user_agent: &str) -> Result<Response, HttpError> {
+        send(url, Client::new().get(url), session, user_agent)
+    }
+
+    pub fn post_form(url: &str, form: &[(&str, String)], session: &Session, user_agent: &str) -> Result<Response, HttpError> {
+        send(url, Client::new().post(url).form(form), session, user_agent)
+    }
+}
+
+#[cfg(not(feature = "fetch"))]
+mod client {
+    use crate::utils::http::{HttpError, Response};
+    use crate::utils::session::Session;
+
+    pub fn get(_url: &str, _session: &Session, _user_agent: &str) -> Result<Response, HttpError> {
+        Err(HttpError::Disabled)
+    }
+
+    pub fn post_form(_url: &str, _form: &[(&str, String)], _session: &Session, _user_agent: &str) -> Result<Response, HttpError> {
+        Err(HttpError::Disabled)
+    }
+}
+
+/// Gets `url` with the session cookie.
+pub fn get(url: &str, session: &Session, user_agent: &str) -> Result<Response, HttpError> {
+    client::get(url, session, user_agent)
+}
+
+/// Posts `form` to `url` with the session cookie.
+pub fn post_form(url: &str, form: &[(&str, String)], session: &Session, user_agent: &str) -> Result<Response, HttpError> {
+    client::post_form(url, form, session, user_agent)
+}
@@ -0,0 +1,84 @@
+use crate::utils::day_error::DayError;
+
+/// An inclusive range of numbers, like the `11-22` of the inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Range {
+    pub start: i64,
+    pub end: i64
+}
+
+impl Range {
+    pub fn new(start: i64, end: i64) -> Range {
+        Range { start, end }
+    }
+
+    /// Parses a range like `11-22`. The errors are at line 1, their column counted from the start of `text`.
+    pub fn parse(text: &str) -> Result<Range, DayError> {
+        let (start_str, end_str) = text.split_once('-')
+            .ok_or_else(|| DayError::parse(1, text, "A range should be two numbers separated by '-'").at_column(1))?;
+        let start = start_str.parse()
+            .map_err(|e| DayError::parse(1, text, "Failed to parse start").at_column(1).with_source(e))?;
+        let end = end_str.parse()
+            .map_err(|e| DayError::parse(1, text, "Failed to parse end").at_column(start_str.len() + 2).with_source(e))?;
+        Ok(Range { start, end })
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Range) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    /// The range covering both, if they overlap.
+    pub fn merge(&self, other: &Range) -> Option<Range> {
+        self.overlaps(other)
+            .then(|| Range { start: self.start.min(other.start), end: self.end.max(other.end) })
+    }
+
+    /// How many numbers are in the range.
+    pub fn size(&self) -> i64 {
+        self.end - self.start + 1
+    }
+}
+
+/// Merges the overlapping ranges, giving ranges that don't overlap sorted by start.
+pub fn merge_all(ranges: &[Range]) -> Vec<Range> {
+    let mut sorted = ranges.to_vec();
+    sorted.sort();
+
+    let mut merged: Vec<Range> = vec![];
+    for range in sorted {
+        match merged.last_mut().and_then(|last| last.merge(&range).map(|union| (last, union))) {
+            Some((last, union)) => *last = union,
+            None => merged.push(range)
+        }
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::range::{merge_all, Range};
+
+    #[test]
+    fn it_test_parse() {
+        let error = Range::parse("95-1a5").expect_err("There should be an error");
+
+        assert_eq!(Range::parse("3-5").expect("There should be a range"), Range::new(3, 5));
+        assert_eq!(error.to_string(), "Parse error at line 1, column 4: Failed to parse end (in `95-1a5`)");
+        assert!(Range::parse("35").is_err());
+    }
+
+    #[test]
+    fn it_test_merge_all() {
+        let ranges = vec![Range::new(10, 14), Range::new(3, 5), Range::new(16, 20), Range::new(12, 18)];
+        let merged = merge_all(&ranges);
+
+        assert_eq!(merged, vec![Range::new(3, 5), Range::new(10, 20)]);
+        assert_eq!(merged.iter().map(Range::size).sum::<i64>(), 14);
+        assert!(merged[1].contains(20));
+        assert!(!merged[0].overlaps(&merged[1]));
+    }
+}
@@ -0,0 +1,144 @@
+use std::env;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use regex::Regex;
+
+use crate::utils::config::{config_path, Config};
+use crate::utils::http::{self, USER_AGENT};
+
+pub const SESSION_FILE: &str = ".aoc-session";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SessionSource {
+    Env,
+    Stdin,
+    Config(PathBuf),
+    File(PathBuf)
+}
+
+impl fmt::Display for SessionSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SessionSource::Env => write!(f, "the AOC_SESSION variable"),
+            SessionSource::Stdin => write!(f, "the standard input"),
+            SessionSource::Config(path) => write!(f, "the config {}", path.display()),
+            SessionSource::File(path) => write!(f, "the file {}", path.display())
+        }
+    }
+}
+
+/// The session cookie of the site, and where it was found.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Session {
+    pub token: String,
+    pub source: SessionSource
+}
+
+/// Accepts the token alone or the whole `session=...` cookie, and ignores the blanks around it.
+pub fn normalize(token: &str) -> Option<String> {
+    let token = token.trim();
+    let token = token.strip_prefix("session=").unwrap_or(token).trim();
+    if token.is_empty() { None } else { Some(token.to_string()) }
+}
+
+/// Looks for the session in `env_value`, then in the config, then in the session file.
+pub fn resolve_from(env_value: Option<String>, config_path: Option<&Path>, session_file: &Path) -> Result<Session, String> {
+    if let Some(token) = env_value.as_deref().and_then(normalize) {
+        return Ok(Session { token, source: SessionSource::Env });
+    }
+
+    if let Some(config_path) = config_path {
+        if let Some(token) = Config::load(config_path)?.session.as_deref().and_then(normalize) {
+            return Ok(Session { token, source: SessionSource::Config(config_path.to_path_buf()) });
+        }
+    }
+
+    if let Some(token) = fs::read_to_string(session_file).ok().as_deref().and_then(normalize) {
+        return Ok(Session { token, source: SessionSource::File(session_file.to_path_buf()) });
+    }
+
+    let config = config_path.map_or("the config".to_string(), |path| path.display().to_string());
+    Err(format!(
+        "No session found. Set AOC_SESSION, add `session = \"...\"` to {config} or write it into {}, or run the login command",
+        session_file.display()
+    ))
+}
+
+/// Looks for the session in `AOC_SESSION`, then in the user config, then in `./.aoc-session`.
+pub fn resolve() -> Result<Session, String> {
+    resolve_from(env::var("AOC_SESSION").ok(), config_path().as_deref(), Path::new(SESSION_FILE))
+}
+
+/// Saves the session and its user into the user config, for the next runs.
+pub fn save(token: &str, user: &str) -> Result<PathBuf, String> {
+    let token = normalize(token).ok_or("The session is empty".to_string())?;
+    let path = config_path().ok_or("No config directory, set AOC_CONFIG or HOME".to_string())?;
+    let mut config = Config::load(&path)?;
+    config.session = Some(token);
+    config.user = Some(user.to_string());
+    config.save(&path)?;
+    Ok(path)
+}
+
+/// Reads the user name shown in the header of any page, only there when logged in.
+pub fn parse_user_name(html: &str) -> Option<String> {
+    let re = Regex::new(r#"<div class="user">([^<]*)"#).expect("Should not have issue with regex");
+    let name = re.captures(html)?.get(1)?.as_str().trim();
+    Some(name.to_string())
+}
+
+/// Checks the session against the site, and gives the name of its user.
+pub fn whoami(base_url: &str, year: i16, session: &Session) -> Result<String, String> {
+    let url = format!("{}/{year}", base_url.trim_end_matches('/'));
+    let response = http::get(&url, session, USER_AGENT)
+        .map_err(|e| e.to_string())?;
+
+    if !response.is_success() {
+        return Err(format!("Unable to check the session, {url} answered {}", response.status));
+    }
+    parse_user_name(&response.body)
+        .ok_or(format!("The session from {} is not logged in, it may have expired", session.source))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+
+    use crate::utils::config::Config;
+    use crate::utils::session::{parse_user_name, resolve_from, SessionSource};
+
+    #[test]
+    fn it_test_resolve_order() {
+        let dir = env::temp_dir().join(format!("aoc_session_{}", std::process::id()));
+        let config_path = dir.join("config.toml");
+        let session_file = dir.join(".aoc-session");
+        fs::create_dir_all(&dir).expect("The directory should be created");
+        fs::write(&session_file, "session=from_file\n").expect("The session file should be written");
+
+        let from_file = resolve_from(None, Some(&config_path), &session_file);
+        Config { session: Some("from_config".to_string()), ..Config::default() }.save(&config_path).expect("The config should be saved");
+        let from_config = resolve_from(Some("  ".to_string()), Some(&config_path), &session_file);
+        let from_env = resolve_from(Some("from_env".to_string()), Some(&config_path), &session_file);
+        fs::remove_dir_all(&dir).expect("The directory should be removed");
+        let missing = resolve_from(None, Some(&config_path), &session_file);
+
+        let from_file = from_file.expect("There should be a session");
+        assert_eq!(from_file.token, "from_file");
+        assert_eq!(from_file.source, SessionSource::File(session_file));
+        assert_eq!(from_config.expect("There should be a session").token, "from_config");
+        assert_eq!(from_env.expect("There should be a session").source, SessionSource::Env);
+        assert!(missing.expect_err("There should be an error").starts_with("No session found"));
+    }
+
+    #[test]
+    fn it_test_parse_user_name() {
+        let logged_in = r#"<header><div class="user">Santa <span class="star-count">24*</span></div></header>"#;
+        let logged_out = r#"<header><div><a href="/2025/auth/login">[Log In]</a></div></header>"#;
+
+        assert_eq!(parse_user_name(logged_in), Some("Santa".to_string()));
+        assert_eq!(parse_user_name(logged_out), None);
+    }
+}
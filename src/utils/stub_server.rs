@@ -0,0 +1,50 @@
+//! A tiny HTTP server for the tests of the clients of the site.
+
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::thread;
+
+/// Reads the headers, then the body announced by `Content-Length`.
+fn read_request(stream: &mut TcpStream) -> String {
+    let mut request = vec![];
+    let mut buffer = [0; 4096];
+    loop {
+        let read = stream.read(&mut buffer).expect("The request should be readable");
+        request.extend_from_slice(&buffer[..read]);
+        let text = String::from_utf8_lossy(&request).to_string();
+        if let Some(headers_end) = text.find("\r\n\r\n") {
+            let length = text.lines()
+                .find_map(|line| line.to_lowercase().strip_prefix("content-length:").map(|l| l.trim().parse::<usize>().unwrap_or(0)))
+                .unwrap_or(0);
+            if request.len() >= headers_end + 4 + length {
+                break;
+            }
+        }
+        if read == 0 {
+            break;
+        }
+    }
+    String::from_utf8_lossy(&request).to_string()
+}
+
+/// Answers each connection with the next `(status, body)` on a random port, and gives back the raw requests it got.
+pub fn serve(responses: Vec<(&'static str, String)>) -> (String, thread::JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("The stub server should bind");
+    let base_url = format!("http://{}", listener.local_addr().expect("The stub server should have an address"));
+    let handle = thread::spawn(move || {
+        let mut requests = vec![];
+        for (status, body) in responses {
+            let (mut stream, _) = listener.accept().expect("The stub server should get a connection");
+            requests.push(read_request(&mut stream));
+            let response = format!("HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}", body.len());
+            stream.write_all(response.as_bytes()).expect("The response should be written");
+        }
+        requests
+    });
+    (base_url, handle)
+}
+
+/// Answers a single connection.
+pub fn serve_once(status: &'static str, body: String) -> (String, thread::JoinHandle<Vec<String>>) {
+    serve(vec![(status, body)])
+}
@@ -0,0 +1,201 @@
+use std::fmt;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::utils::http::{self, USER_AGENT};
+use crate::utils::session::Session;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Hint {
+    TooHigh,
+    TooLow
+}
+
+/// What the site answered to a submission. The waits are in seconds.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Outcome {
+    Right,
+    Wrong { hint: Option<Hint>, wait_secs: Option<u64> },
+    TooRecent { wait_secs: Option<u64> },
+    AlreadySolved,
+    Unknown(String)
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Right => write!(f, "That's the right answer!"),
+            Outcome::Wrong { hint, wait_secs } => {
+                write!(f, "That's not the right answer")?;
+                match hint {
+                    Some(Hint::TooHigh) => write!(f, ", it is too high")?,
+                    Some(Hint::TooLow) => write!(f, ", it is too low")?,
+                    None => {}
+                }
+                if let Some(wait_secs) = wait_secs {
+                    write!(f, ". Wait {wait_secs}s before trying again")?;
+                }
+                Ok(())
+            },
+            Outcome::TooRecent { wait_secs: Some(wait_secs) } => write!(f, "An answer was given too recently, wait {wait_secs}s"),
+            Outcome::TooRecent { wait_secs: None } => write!(f, "An answer was given too recently"),
+            Outcome::AlreadySolved => write!(f, "This part is already solved, or its previous part isn't"),
+            Outcome::Unknown(text) => write!(f, "Unexpected response: {text}")
+        }
+    }
+}
+
+/// Reads a wait like `1m 23s`, `34s` or `5m` in seconds.
+fn parse_wait(value: &str) -> Option<u64> {
+    let re = Regex::new(r"^(?:(\d+)m)?\s*(?:(\d+)s)?$").expect("Should not have issue with regex");
+    let captures = re.captures(value.trim())?;
+    let minutes = captures.get(1).map_or(Ok(0), |m| m.as_str().parse::<u64>()).ok()?;
+    let seconds = captures.get(2).map_or(Ok(0), |s| s.as_str().parse::<u64>()).ok()?;
+    if captures.get(1).is_none() && captures.get(2).is_none() {
+        return None;
+    }
+    Some(minutes * 60 + seconds)
+}
+
+/// Reads the `please wait one minute` or `wait 5 minutes` that follows a wrong answer, in seconds.
+fn parse_penalty(text: &str) -> Option<u64> {
+    let re = Regex::new(r"wait (one|\d+) minutes?").expect("Should not have issue with regex");
+    let amount = re.captures(text)?.get(1)?.as_str();
+    let minutes = if amount == "one" { 1 } else { amount.parse::<u64>().ok()? };
+    Some(minutes * 60)
+}
+
+/// The text of the `<article>` of the response page, without its tags.
+fn article_text(html: &str) -> String {
+    let article = match (html.find("<article"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html
+    };
+    let tags = Regex::new(r"<[^>]*>").expect("Should not have issue with regex");
+    let text = tags.replace_all(article, "");
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+/// Reads the response page of a submission.
+pub fn parse_response(html: &str) -> Outcome {
+    let text = article_text(html);
+
+    if text.contains("That's the right answer") {
+        Outcome::Right
+    } else if text.contains("That's not the right answer") {
+        let hint = if text.contains("too high") {
+            Some(Hint::TooHigh)
+        } else if text.contains("too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+        Outcome::Wrong { hint, wait_secs: parse_penalty(&text) }
+    } else if text.contains("You gave an answer too recently") {
+        let wait = Regex::new(r"You have (.+?) left to wait").expect("Should not have issue with regex");
+        let wait_secs = wait.captures(&text)
+            .and_then(|captures| parse_wait(captures.get(1)?.as_str()));
+        Outcome::TooRecent { wait_secs }
+    } else if text.contains("Did you already complete it") {
+        Outcome::AlreadySolved
+    } else {
+        Outcome::Unknown(text)
+    }
+}
+
+/// Posts answers to the site. The base URL can point to a local server for the tests.
+pub struct SubmitClient {
+    base_url: String,
+    session: Session
+}
+
+impl SubmitClient {
+    pub fn new(base_url: &str, session: Session) -> SubmitClient {
+        SubmitClient { base_url: base_url.trim_end_matches('/').to_string(), session }
+    }
+
+    pub fn submit(&self, year: i16, day: i8, part: i8, answer: &str) -> Result<Outcome, String> {
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let form = [("level", part.to_string()), ("answer", answer.to_string())];
+        let response = http::post_form(&url, &form, &self.session, USER_AGENT)
+            .map_err(|e| format!("Unable to post the answer: {e}"))?;
+
+        if !response.is_success() {
+            return Err(format!("The answer was refused by {url}: {}", response.status));
+        }
+        Ok(parse_response(&response.body))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::session::{Session, SessionSource};
+    #[cfg(feature = "fetch")]
+    use crate::utils::stub_server::serve_once;
+    use crate::utils::submit::{parse_response, Hint, Outcome, SubmitClient};
+
+    fn session() -> Session {
+        Session { token: "abc123".to_string(), source: SessionSource::Env }
+    }
+
+    fn page(article: &str) -> String {
+        format!("<html><body><main><article><p>{article}</p></article></main></body></html>")
+    }
+
+    #[test]
+    fn it_test_parse_response() {
+        assert_eq!(parse_response(&page("That's the right answer! You are one gold star closer.")), Outcome::Right);
+        assert_eq!(
+            parse_response(&page("That's not the right answer; your answer is too high. Please wait one minute before trying again.")),
+            Outcome::Wrong { hint: Some(Hint::TooHigh), wait_secs: Some(60) }
+        );
+        assert_eq!(
+            parse_response(&page("That's not the right answer. please wait 5 minutes before trying again.")),
+            Outcome::Wrong { hint: None, wait_secs: Some(300) }
+        );
+        assert_eq!(
+            parse_response(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 1m 23s left to wait.")),
+            Outcome::TooRecent { wait_secs: Some(83) }
+        );
+        assert_eq!(
+            parse_response(&page("You don't seem to be solving the right level. Did you already complete it?")),
+            Outcome::AlreadySolved
+        );
+        assert!(matches!(parse_response("<html>Something else</html>"), Outcome::Unknown(_)));
+    }
+
+    #[cfg(feature = "fetch")]
+    #[test]
+    fn it_test_submit_to_stub_server() {
+        let (base_url, server) = serve_once("200 OK", page("That's not the right answer; your answer is <em>too low</em>."));
+        let outcome = SubmitClient::new(&base_url, session()).submit(2025, 4, 2, "1564")
+            .expect("There should be an outcome");
+        let request = &server.join().expect("The stub server should not panic")[0];
+
+        assert_eq!(outcome, Outcome::Wrong { hint: Some(Hint::TooLow), wait_secs: None });
+        assert!(request.starts_with("POST /2025/day/4/answer HTTP/1.1"));
+        assert!(request.contains("cookie: session=abc123"));
+        assert!(request.ends_with("level=2&answer=1564"));
+    }
+
+    #[cfg(feature = "fetch")]
+    #[test]
+    fn it_test_submit_error_status() {
+        let (base_url, server) = serve_once("500 Internal Server Error", "Oops".to_string());
+        let result = SubmitClient::new(&base_url, session()).submit(2025, 4, 1, "1");
+        server.join().expect("The stub server should not panic");
+
+        assert!(result.expect_err("There should be an error").contains("500"));
+    }
+
+    #[cfg(not(feature = "fetch"))]
+    #[test]
+    fn it_test_submit_without_fetch() {
+        let result = SubmitClient::new("http://127.0.0.1:1", session()).submit(2025, 4, 1, "1");
+
+        assert!(result.expect_err("There should be an error").contains("fetch feature"));
+    }
+}
@@ -0,0 +1,208 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// The label of the runs on the real input, the runs on an example being labelled by its file.
+pub const INPUT_LABEL: &str = "input";
+
+/// A part run by `watch --once`, printed as one line so the watch loop can read it back.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartRun {
+    pub label: String,
+    pub part: i8,
+    /// The answer, or the error of the part.
+    pub outcome: Result<String, String>,
+    pub expected: Option<String>,
+    pub elapsed: Duration
+}
+
+/// Keeps a value on a single line without tabs.
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('\n', "\\n").replace('\t', "\\t")
+}
+
+fn unescape(value: &str) -> String {
+    let mut result = String::new();
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => result.push('\n'),
+            Some('t') => result.push('\t'),
+            Some(other) => result.push(other),
+            None => result.push('\\')
+        }
+    }
+    result
+}
+
+impl PartRun {
+    /// `run <label> <part> <ok|err> <answer or error> <expected> <nanoseconds>`, separated by tabs.
+    pub fn to_line(&self) -> String {
+        let (status, value) = match &self.outcome {
+            Ok(answer) => ("ok", answer),
+            Err(message) => ("err", message)
+        };
+        format!(
+            "run\t{}\t{}\t{status}\t{}\t{}\t{}",
+            escape(&self.label),
+            self.part,
+            escape(value),
+            self.expected.as_deref().map(escape).unwrap_or_default(),
+            self.elapsed.as_nanos()
+        )
+    }
+
+    /// Reads a line written by `to_line`, `None` for the other lines of the output.
+    pub fn from_line(line: &str) -> Option<PartRun> {
+        let fields: Vec<&str> = line.strip_prefix("run\t")?.split('\t').collect();
+        let [label, part, status, value, expected, nanos] = fields.as_slice() else {
+            return None;
+        };
+        let outcome = match *status {
+            "ok" => Ok(unescape(value)),
+            "err" => Err(unescape(value)),
+            _ => return None
+        };
+        Some(PartRun {
+            label: unescape(label),
+            part: part.parse().ok()?,
+            outcome,
+            expected: (!expected.is_empty()).then(|| unescape(expected)),
+            elapsed: Duration::from_nanos(nanos.parse().ok()?)
+        })
+    }
+
+    /// Whether the answer is the expected one, when there is one.
+    pub fn passed(&self) -> Option<bool> {
+        self.expected.as_ref().map(|expected| self.outcome.as_ref() == Ok(expected))
+    }
+}
+
+/// One line for the run, with what changed since the previous run of the same example and part.
+pub fn describe(run: &PartRun, previous: Option<&PartRun>) -> String {
+    let mut line = format!("{:<16} part {}  ", run.label, run.part);
+    match &run.outcome {
+        Ok(answer) if answer.contains('\n') => line.push_str(&format!("\n{answer}\n")),
+        Ok(answer) => line.push_str(answer),
+        Err(message) => line.push_str(&format!("ERROR {message}"))
+    }
+    match (run.passed(), &run.expected) {
+        (Some(true), _) => line.push_str("  PASS"),
+        (Some(false), Some(expected)) => line.push_str(&format!("  FAIL (expected {expected})")),
+        _ => {}
+    }
+
+    match previous {
+        Some(previous) if previous.outcome != run.outcome => {
+            let was = match &previous.outcome {
+                Ok(answer) => answer.clone(),
+                Err(_) => "an error".to_string()
+            };
+            line.push_str(&format!("  changed, was {was}"));
+        },
+        Some(_) => {},
+        None => line.push_str("  new")
+    }
+
+    line.push_str(&format!("  in {:.2?}", run.elapsed));
+    if let Some(previous) = previous {
+        line.push_str(&format!(" (was {:.2?})", previous.elapsed));
+    }
+    line
+}
+
+/// The files to watch for a day: its source, its input and its examples.
+pub fn watched_files(src_dir: &Path, examples_dir: &Path, year: i16, day: i8, input_path: &Path) -> Vec<PathBuf> {
+    let mut paths = vec![
+        src_dir.join("days").join(format!("y{year}")).join(format!("day{day}.rs")),
+        input_path.to_path_buf()
+    ];
+    let example_prefix = format!("day{day}_");
+    let answers_file = format!("day{day}.json");
+    if let Ok(entries) = fs::read_dir(examples_dir) {
+        let mut examples: Vec<PathBuf> = entries.flatten()
+            .filter(|entry| entry.file_name().to_str()
+                .is_some_and(|name| name.starts_with(&example_prefix) || name == answers_file))
+            .map(|entry| entry.path())
+            .collect();
+        examples.sort();
+        paths.extend(examples);
+    }
+    paths
+}
+
+/// The modification time of each file, `None` when it doesn't exist.
+pub fn modification_times(paths: &[PathBuf]) -> BTreeMap<PathBuf, Option<SystemTime>> {
+    paths.iter()
+        .map(|path| (path.clone(), fs::metadata(path).and_then(|metadata| metadata.modified()).ok()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::time::Duration;
+
+    use crate::watch::{describe, modification_times, watched_files, PartRun};
+
+    fn run(outcome: Result<&str, &str>, expected: Option<&str>, micros: u64) -> PartRun {
+        PartRun {
+            label: "day4_1".to_string(),
+            part: 1,
+            outcome: outcome.map(str::to_string).map_err(str::to_string),
+            expected: expected.map(str::to_string),
+            elapsed: Duration::from_micros(micros)
+        }
+    }
+
+    #[test]
+    fn it_test_line_round_trip() {
+        let runs = vec![
+            run(Ok("#.#\n.\t#\\"), Some("13"), 25),
+            run(Err("The part 1 is not solved yet"), None, 3),
+            run(Ok(""), None, 0)
+        ];
+
+        for part_run in runs {
+            assert_eq!(PartRun::from_line(&part_run.to_line()), Some(part_run));
+        }
+        assert_eq!(PartRun::from_line("Reading input file..."), None);
+        assert_eq!(PartRun::from_line("run\tday4_1\t1"), None);
+    }
+
+    #[test]
+    fn it_test_describe() {
+        let previous = run(Ok("12"), Some("13"), 30);
+        let current = run(Ok("13"), Some("13"), 25);
+
+        assert_eq!(describe(&current, Some(&previous)), "day4_1           part 1  13  PASS  changed, was 12  in 25.00µs (was 30.00µs)");
+        assert_eq!(describe(&previous, None), "day4_1           part 1  12  FAIL (expected 13)  new  in 30.00µs");
+        assert_eq!(describe(&run(Err("Oops"), None, 1), Some(&current)), "day4_1           part 1  ERROR Oops  changed, was 13  in 1.00µs (was 25.00µs)");
+    }
+
+    #[test]
+    fn it_test_watched_files() {
+        let dir = env::temp_dir().join(format!("aoc_watch_{}", std::process::id()));
+        fs::create_dir_all(&dir).expect("The directory should be created");
+        for file in ["day1_1.txt", "day1.json", "day10_1.txt", "day1_edge.txt"] {
+            fs::write(dir.join(file), "").expect("The file should be written");
+        }
+
+        let paths = watched_files(&dir.join("src"), &dir, 2025, 1, &dir.join("input.txt"));
+        let times = modification_times(&paths);
+        fs::remove_dir_all(&dir).expect("The directory should be removed");
+
+        let names: Vec<String> = paths.iter()
+            .map(|path| path.file_name().and_then(|name| name.to_str()).unwrap_or_default().to_string())
+            .collect();
+        assert_eq!(names, vec!["day1.rs", "input.txt", "day1.json", "day1_1.txt", "day1_edge.txt"]);
+        assert_eq!(times[&paths[0]], None);
+        assert!(times[&paths[2]].is_some());
+    }
+}
@@ -0,0 +1,52 @@
+//! Runs every registered day against the examples saved in `examples/<YEAR>`, one test per example and part.
+//! To add a test, save its input next to the others and its expected answers in `day<N>.json`.
+
+use libtest_mimic::{Arguments, Failed, Trial};
+
+use aoc_rust::day_factory::{all_days, DayEntry};
+use aoc_rust::utils::day::Solver;
+use aoc_rust::utils::examples::{examples_dir, load_examples};
+
+fn check_part(solver: &dyn Solver, input: &str, part: i8, expected: &str) -> Result<(), Failed> {
+    let parsed = solver.parse(input)
+        .map_err(|error| format!("The example couldn't be parsed: {error}"))?;
+    let answer = solver.run_part(part, &parsed)
+        .map_err(|error| format!("The part {part} failed: {error}"))?;
+
+    if answer.to_string() == expected {
+        Ok(())
+    } else {
+        Err(format!("Expected {expected}, found {answer}").into())
+    }
+}
+
+fn day_trials(entry: &'static DayEntry) -> Vec<Trial> {
+    let prefix = format!("y{}::day{}", entry.year, entry.day());
+    let examples = match load_examples(&examples_dir(entry.year), entry.day()) {
+        Ok(examples) => examples,
+        Err(message) => return vec![Trial::test(format!("{prefix}::load"), move || Err(message.into()))]
+    };
+
+    let mut trials = vec![];
+    for (file, example) in examples {
+        let name = file.trim_end_matches(".txt").to_string();
+        for (part, expected) in [(1, example.part1), (2, example.part2)] {
+            let Some(expected) = expected else {
+                continue;
+            };
+            let input = example.input.clone();
+            trials.push(Trial::test(format!("{prefix}::{name}::part{part}"), move || {
+                check_part(entry.solver, &input, part, &expected)
+            }));
+        }
+    }
+    trials
+}
+
+fn main() {
+    let args = Arguments::from_args();
+    let trials = all_days().into_iter()
+        .flat_map(day_trials)
+        .collect();
+    libtest_mimic::run(&args, trials).exit();
+}